@@ -14,8 +14,22 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+pub mod capacitance_unit;
+pub mod electric_charge_unit;
+pub mod electric_potential_unit;
+pub mod electric_resistance_unit;
 pub mod energy_unit;
+pub mod force_unit;
+pub mod frequency_unit;
+pub mod gauge_pressure;
+pub mod inductance_unit;
+pub mod magnetic_flux_density_unit;
+pub mod magnetic_flux_unit;
+pub mod power_unit;
+pub mod pressure_unit;
+pub mod viscosity_unit;
 
+use crate::error::{check_finite, EngUnitError};
 use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
 use crate::units::electric_current_unit::ElectricCurrentUnit;
 use crate::units::length_unit::LengthUnit;
@@ -49,94 +63,259 @@ impl ComplexUnit {
     pub fn unit_to_string(&self) -> String {
         self.unit_string.to_string()
     }
+
+    /// Returns a copy of the unit scaled by an SI prefix, e.g.
+    /// `NEWTON.with_prefix(1.0 / 1000.0, "kN")`.
+    pub const fn with_prefix(
+        self,
+        prefix_multiplier: f64,
+        unit_string: &'static str,
+    ) -> ComplexUnit {
+        ComplexUnit {
+            prefix_multiplier,
+            unit_string,
+            ..self
+        }
+    }
+
+    /// Returns `true` if both units have the same dimension exponents, e.g.
+    /// `kW` and `hp`.
+    pub(crate) fn same_dimensions(&self, other: &ComplexUnit) -> bool {
+        self.amount_of_substance_count == other.amount_of_substance_count
+            && self.electric_current_count == other.electric_current_count
+            && self.length_count == other.length_count
+            && self.luminous_intensity_count == other.luminous_intensity_count
+            && self.mass_count == other.mass_count
+            && self.temperature_count == other.temperature_count
+            && self.time_count == other.time_count
+    }
+
+    /// Value of one `self` in `to`, a unit of the same dimension.
+    pub(crate) fn scale_to(&self, to: &ComplexUnit) -> f64 {
+        from_complex_unit(1.0, *self).to_si_units().value
+            / from_complex_unit(1.0, *to).to_si_units().value
+    }
+}
+
+/// Every named unit known to the crate, in registry order.
+pub const COMPLEX_UNITS: &[ComplexUnit] = &[
+    energy_unit::JOULE,
+    energy_unit::KILOJOULE,
+    energy_unit::MEGAJOULE,
+    energy_unit::GIGAJOULE,
+    energy_unit::TERAJOULE,
+    energy_unit::WATT_HOUR,
+    energy_unit::KILOWATT_HOUR,
+    energy_unit::MEGAWATT_HOUR,
+    energy_unit::BTU,
+    energy_unit::BTU_THERMOCHEMICAL,
+    energy_unit::CALORIE,
+    energy_unit::KILOCALORIE,
+    energy_unit::CALORIE_IT,
+    energy_unit::KILOCALORIE_IT,
+    energy_unit::THERM,
+    energy_unit::ERG,
+    energy_unit::ELECTRONVOLT,
+    energy_unit::FOOT_POUND_FORCE,
+    power_unit::WATT,
+    power_unit::MILLIWATT,
+    power_unit::KILOWATT,
+    power_unit::MEGAWATT,
+    power_unit::GIGAWATT,
+    power_unit::TERAWATT,
+    power_unit::HORSEPOWER,
+    power_unit::METRIC_HORSEPOWER,
+    force_unit::NEWTON,
+    force_unit::MILLINEWTON,
+    force_unit::KILONEWTON,
+    force_unit::MEGANEWTON,
+    force_unit::POUND_FORCE,
+    force_unit::DYNE,
+    force_unit::KILOGRAM_FORCE,
+    force_unit::POUNDAL,
+    pressure_unit::PASCAL,
+    pressure_unit::HECTOPASCAL,
+    pressure_unit::KILOPASCAL,
+    pressure_unit::MEGAPASCAL,
+    pressure_unit::GIGAPASCAL,
+    pressure_unit::PSI,
+    pressure_unit::BARYE,
+    pressure_unit::BAR,
+    pressure_unit::MILLIBAR,
+    pressure_unit::ATMOSPHERE,
+    pressure_unit::TORR,
+    pressure_unit::MILLIMETER_MERCURY,
+    pressure_unit::INCH_WATER,
+    frequency_unit::HERTZ,
+    frequency_unit::KILOHERTZ,
+    frequency_unit::MEGAHERTZ,
+    frequency_unit::GIGAHERTZ,
+    electric_charge_unit::COULOMB,
+    electric_charge_unit::MILLICOULOMB,
+    electric_charge_unit::MICROCOULOMB,
+    electric_charge_unit::KILOCOULOMB,
+    electric_potential_unit::VOLT,
+    electric_potential_unit::MILLIVOLT,
+    electric_potential_unit::KILOVOLT,
+    electric_potential_unit::MEGAVOLT,
+    electric_resistance_unit::OHM,
+    electric_resistance_unit::MILLIOHM,
+    electric_resistance_unit::KILOOHM,
+    electric_resistance_unit::MEGAOHM,
+    capacitance_unit::FARAD,
+    capacitance_unit::MILLIFARAD,
+    capacitance_unit::MICROFARAD,
+    capacitance_unit::NANOFARAD,
+    capacitance_unit::PICOFARAD,
+    inductance_unit::HENRY,
+    inductance_unit::MILLIHENRY,
+    inductance_unit::MICROHENRY,
+    magnetic_flux_density_unit::TESLA,
+    magnetic_flux_density_unit::MILLITESLA,
+    magnetic_flux_density_unit::MICROTESLA,
+    magnetic_flux_unit::WEBER,
+    magnetic_flux_unit::MILLIWEBER,
+    viscosity_unit::POISE,
+    viscosity_unit::CENTIPOISE,
+];
+
+/// Looks up a named unit in [`COMPLEX_UNITS`] by its symbol.
+pub fn find_complex_unit(symbol: &str) -> Option<ComplexUnit> {
+    COMPLEX_UNITS
+        .iter()
+        .find(|complex| complex.unit_string == symbol)
+        .copied()
 }
 
 pub fn can_pop_numerator(unit: &EngUnit, complex: &ComplexUnit) -> bool {
-    if complex.amount_of_substance_count > 0 {
-        if unit.amount_of_substance_count < complex.amount_of_substance_count {
-            return false;
-        }
-    } else if complex.amount_of_substance_count < 0 {
-        if unit.amount_of_substance_count > complex.amount_of_substance_count {
-            return false;
-        }
+    if (complex.amount_of_substance_count > 0
+        && unit.amount_of_substance_count < complex.amount_of_substance_count)
+        || (complex.amount_of_substance_count < 0
+            && unit.amount_of_substance_count > complex.amount_of_substance_count)
+    {
+        return false;
     }
 
-    if complex.electric_current_count > 0 {
-        if unit.electric_current_count < complex.electric_current_count {
-            return false;
-        }
-    } else if complex.electric_current_count < 0 {
-        if unit.electric_current_count > complex.electric_current_count {
-            return false;
-        }
+    if (complex.electric_current_count > 0
+        && unit.electric_current_count < complex.electric_current_count)
+        || (complex.electric_current_count < 0
+            && unit.electric_current_count > complex.electric_current_count)
+    {
+        return false;
     }
 
-    if complex.length_count > 0 {
-        if unit.length_count < complex.length_count {
-            return false;
-        }
-    } else if complex.length_count < 0 {
-        if unit.length_count > complex.length_count {
-            return false;
-        }
+    if (complex.length_count > 0 && unit.length_count < complex.length_count)
+        || (complex.length_count < 0 && unit.length_count > complex.length_count)
+    {
+        return false;
     }
 
-    if complex.luminous_intensity_count > 0 {
-        if unit.luminous_intensity_count < complex.luminous_intensity_count {
-            return false;
-        }
-    } else if complex.luminous_intensity_count < 0 {
-        if unit.luminous_intensity_count > complex.luminous_intensity_count {
-            return false;
-        }
+    if (complex.luminous_intensity_count > 0
+        && unit.luminous_intensity_count < complex.luminous_intensity_count)
+        || (complex.luminous_intensity_count < 0
+            && unit.luminous_intensity_count > complex.luminous_intensity_count)
+    {
+        return false;
     }
 
-    if complex.mass_count > 0 {
-        if unit.mass_count < complex.mass_count {
-            return false;
-        }
-    } else if complex.mass_count < 0 {
-        if unit.mass_count > complex.mass_count {
-            return false;
-        }
+    if (complex.mass_count > 0 && unit.mass_count < complex.mass_count)
+        || (complex.mass_count < 0 && unit.mass_count > complex.mass_count)
+    {
+        return false;
     }
 
-    if complex.temperature_count > 0 {
-        if unit.temperature_count < complex.temperature_count {
-            return false;
-        }
-    } else if complex.temperature_count < 0 {
-        if unit.temperature_count > complex.temperature_count {
-            return false;
-        }
+    if (complex.temperature_count > 0 && unit.temperature_count < complex.temperature_count)
+        || (complex.temperature_count < 0 && unit.temperature_count > complex.temperature_count)
+    {
+        return false;
     }
 
-    if complex.time_count > 0 {
-        if unit.time_count < complex.time_count {
-            return false;
-        }
-    } else if complex.time_count < 0 {
-        if unit.time_count > complex.time_count {
-            return false;
-        }
+    if (complex.time_count > 0 && unit.time_count < complex.time_count)
+        || (complex.time_count < 0 && unit.time_count > complex.time_count)
+    {
+        return false;
     }
     true
 }
 
+/// `true` if the base exponents of `unit` contain the inverse of `complex`,
+/// so that `complex` can be moved into the denominator.
+pub fn can_pop_denominator(unit: &EngUnit, complex: &ComplexUnit) -> bool {
+    can_pop_numerator(unit, &inverse(complex))
+}
+
+fn inverse(complex: &ComplexUnit) -> ComplexUnit {
+    ComplexUnit {
+        amount_of_substance_count: -complex.amount_of_substance_count,
+        electric_current_count: -complex.electric_current_count,
+        length_count: -complex.length_count,
+        luminous_intensity_count: -complex.luminous_intensity_count,
+        mass_count: -complex.mass_count,
+        temperature_count: -complex.temperature_count,
+        time_count: -complex.time_count,
+        ..*complex
+    }
+}
+
+/// Converts the dimensions that `complex` is made of into its base units.
+/// Dimensions `complex` does not use keep their units.
+fn convert_to_base_units(unit: &EngUnit, complex: &ComplexUnit) -> EngUnit {
+    let mut new_unit = unit.clone();
+    if complex.amount_of_substance_count != 0 {
+        new_unit = new_unit.convert(complex.amount_of_substance_unit);
+    }
+    if complex.electric_current_count != 0 {
+        new_unit = new_unit.convert(complex.electric_current_unit);
+    }
+    if complex.length_count != 0 {
+        new_unit = new_unit.convert(complex.length_unit);
+    }
+    if complex.luminous_intensity_count != 0 {
+        new_unit = new_unit.convert(complex.luminous_intensity_unit);
+    }
+    if complex.mass_count != 0 {
+        new_unit = new_unit.convert(complex.mass_unit);
+    }
+    if complex.temperature_count != 0 {
+        new_unit = new_unit.convert(complex.temperature_unit);
+    }
+    if complex.time_count != 0 {
+        new_unit = new_unit.convert(complex.time_unit);
+    }
+    new_unit
+}
+
+/// Creates a unit of `value` expressed directly in `complex`, e.g. `12.3 kJ`.
+pub fn from_complex_unit(value: f64, complex: ComplexUnit) -> EngUnit {
+    let mut unit = EngUnit::new();
+    unit.value = value;
+    unit.unit_numerator.push(complex);
+    unit
+}
+
+/// Like [`push_complex_numerator`] but reports why the unit could not be
+/// taken out of `unit`.
+pub fn try_push_complex_numerator(
+    unit: &EngUnit,
+    complex: ComplexUnit,
+) -> Result<EngUnit, EngUnitError> {
+    match push_complex_numerator(unit, complex) {
+        Some(new_unit) => {
+            check_finite(new_unit.value)?;
+            Ok(new_unit)
+        }
+        None => Err(EngUnitError::IncompatibleComplexUnit {
+            unit: unit.unit_to_string(),
+            complex: complex.unit_to_string(),
+        }),
+    }
+}
+
 pub fn push_complex_numerator(unit: &EngUnit, complex: ComplexUnit) -> Option<EngUnit> {
     if !can_pop_numerator(unit, &complex) {
         return None;
     }
-    let new_unit = unit.convert(complex.amount_of_substance_unit);
-    let new_unit = new_unit.convert(complex.electric_current_unit);
-    let new_unit = new_unit.convert(complex.length_unit);
-    let new_unit = new_unit.convert(complex.luminous_intensity_unit);
-    let new_unit = new_unit.convert(complex.mass_unit);
-    let new_unit = new_unit.convert(complex.temperature_unit);
-    let new_unit = new_unit.convert(complex.time_unit);
-
-    let mut new_unit = new_unit;
+    let mut new_unit = convert_to_base_units(unit, &complex);
     new_unit.value *= complex.prefix_multiplier;
     new_unit.amount_of_substance_count -= complex.amount_of_substance_count;
     new_unit.electric_current_count -= complex.electric_current_count;
@@ -151,15 +330,7 @@ pub fn push_complex_numerator(unit: &EngUnit, complex: ComplexUnit) -> Option<En
 }
 
 pub fn pop_complex_numerator(unit: &EngUnit, complex: ComplexUnit) -> EngUnit {
-    let new_unit = unit.convert(complex.amount_of_substance_unit);
-    let new_unit = new_unit.convert(complex.electric_current_unit);
-    let new_unit = new_unit.convert(complex.length_unit);
-    let new_unit = new_unit.convert(complex.luminous_intensity_unit);
-    let new_unit = new_unit.convert(complex.mass_unit);
-    let new_unit = new_unit.convert(complex.temperature_unit);
-    let new_unit = new_unit.convert(complex.time_unit);
-
-    let mut new_unit = new_unit;
+    let mut new_unit = convert_to_base_units(unit, &complex);
     new_unit.value /= complex.prefix_multiplier;
     new_unit.amount_of_substance_count += complex.amount_of_substance_count;
     new_unit.electric_current_count += complex.electric_current_count;
@@ -169,10 +340,160 @@ pub fn pop_complex_numerator(unit: &EngUnit, complex: ComplexUnit) -> EngUnit {
     new_unit.temperature_count += complex.temperature_count;
     new_unit.time_count += complex.time_count;
 
-    let index = new_unit.unit_numerator.iter().position(|x| *x == complex);
-    if index.is_some() {
-        let index = index.unwrap();
+    if let Some(index) = new_unit.unit_numerator.iter().position(|x| *x == complex) {
         new_unit.unit_numerator.remove(index);
     }
     new_unit
 }
+
+/// Like [`push_complex_denominator`] but reports why the unit could not be
+/// taken out of `unit`.
+pub fn try_push_complex_denominator(
+    unit: &EngUnit,
+    complex: ComplexUnit,
+) -> Result<EngUnit, EngUnitError> {
+    match push_complex_denominator(unit, complex) {
+        Some(new_unit) => {
+            check_finite(new_unit.value)?;
+            Ok(new_unit)
+        }
+        None => Err(EngUnitError::IncompatibleComplexUnit {
+            unit: unit.unit_to_string(),
+            complex: complex.unit_to_string(),
+        }),
+    }
+}
+
+pub fn push_complex_denominator(unit: &EngUnit, complex: ComplexUnit) -> Option<EngUnit> {
+    if !can_pop_denominator(unit, &complex) {
+        return None;
+    }
+    let mut new_unit = convert_to_base_units(unit, &complex);
+    new_unit.value /= complex.prefix_multiplier;
+    new_unit.amount_of_substance_count += complex.amount_of_substance_count;
+    new_unit.electric_current_count += complex.electric_current_count;
+    new_unit.length_count += complex.length_count;
+    new_unit.luminous_intensity_count += complex.luminous_intensity_count;
+    new_unit.mass_count += complex.mass_count;
+    new_unit.temperature_count += complex.temperature_count;
+    new_unit.time_count += complex.time_count;
+    new_unit.unit_denominator.push(complex);
+    Some(new_unit)
+}
+
+pub fn pop_complex_denominator(unit: &EngUnit, complex: ComplexUnit) -> EngUnit {
+    let mut new_unit = convert_to_base_units(unit, &complex);
+    new_unit.value *= complex.prefix_multiplier;
+    new_unit.amount_of_substance_count -= complex.amount_of_substance_count;
+    new_unit.electric_current_count -= complex.electric_current_count;
+    new_unit.length_count -= complex.length_count;
+    new_unit.luminous_intensity_count -= complex.luminous_intensity_count;
+    new_unit.mass_count -= complex.mass_count;
+    new_unit.temperature_count -= complex.temperature_count;
+    new_unit.time_count -= complex.time_count;
+    if let Some(index) = new_unit.unit_denominator.iter().position(|x| *x == complex) {
+        new_unit.unit_denominator.remove(index);
+    }
+    new_unit
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::complex_units::energy_unit::KILOJOULE;
+    use crate::complex_units::*;
+    use crate::testing::assert_close;
+    use crate::units::EngUnit;
+    use crate::{kJ, kW, length, mass, temperature, J, W};
+
+    #[test]
+    fn specific_heat() {
+        let m = mass!(2.0, MassUnit::Kilogram);
+        let t = temperature!(1.0, TemperatureDeltaUnit::K);
+        let cp = kJ!(4.2) / m / t;
+        assert_eq!("2.10 kJ/kg·K", cp.to_string());
+        assert_close(2100.0, cp.to_si_units().value);
+    }
+
+    #[test]
+    fn push_denominator() {
+        let mut inverse = kJ!(1.0).reciprocal();
+        inverse.value = 5.0;
+        let inverse = inverse.to_si_units();
+        assert!(can_pop_denominator(&inverse, &KILOJOULE));
+        assert!(!can_pop_numerator(&inverse, &KILOJOULE));
+        let pushed = push_complex_denominator(&inverse, KILOJOULE).unwrap();
+        assert_eq!(vec![KILOJOULE], pushed.unit_denominator);
+        assert_close(5.0, pushed.value);
+        assert_eq!("5.00 /kJ", pushed.to_string());
+
+        let l = length!(1.0, LengthUnit::Meter);
+        assert!(!can_pop_denominator(&l, &KILOJOULE));
+        assert!(try_push_complex_denominator(&l, KILOJOULE).is_err());
+    }
+
+    #[test]
+    fn pop_denominator() {
+        let unit = kJ!(1.0).reciprocal();
+        assert_eq!(vec![KILOJOULE], unit.unit_denominator);
+        assert!(unit.unit_numerator.is_empty());
+        let popped = pop_complex_denominator(&unit, KILOJOULE);
+        assert!(popped.unit_denominator.is_empty());
+        assert_close(0.001, popped.value);
+        assert_eq!(-2, popped.length_count);
+        assert_eq!(-1, popped.mass_count);
+        assert_eq!(2, popped.time_count);
+    }
+
+    #[test]
+    fn reciprocal_moves_complex_units() {
+        let unit = kJ!(2.0) / mass!(1.0, MassUnit::Kilogram);
+        let recip = unit.reciprocal();
+        assert!(recip.unit_numerator.is_empty());
+        assert_eq!(vec![KILOJOULE], recip.unit_denominator);
+        assert_eq!("0.50 kg/kJ", recip.to_string());
+    }
+
+    #[test]
+    fn multiply_cancels_complex_units() {
+        let unit = kJ!(6.0) / kJ!(2.0);
+        assert!(unit.unit_numerator.is_empty());
+        assert!(unit.unit_denominator.is_empty());
+        assert_eq!("3.00", unit.to_string());
+
+        let ratio = kW!(1.0) / W!(1.0);
+        assert_eq!("1000.00", ratio.to_string());
+        assert!(!ratio.has_units());
+
+        let unit = kJ!(1.0) * W!(2.0) / (J!(1.0) * kW!(1.0));
+        assert!(!unit.has_units());
+        assert_close(2.0, unit.value);
+
+        let unit = kJ!(3.0) / W!(1.0) / mass!(1.0, MassUnit::Kilogram);
+        assert_eq!("3.00 kJ/W·kg", unit.to_string());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let s = crate::time!(1.0, TimeUnit::Second);
+        for complex in COMPLEX_UNITS {
+            let x = from_complex_unit(2.0, *complex);
+            for unit in [x.clone(), x.clone() * x.clone(), x.clone() / s.clone()] {
+                for text in [unit.to_string(), unit.to_ascii_string()] {
+                    let parsed: EngUnit = text.parse().unwrap();
+                    assert_eq!(unit.to_string(), parsed.to_string(), "{text}");
+                    assert_eq!(unit.unit_numerator, parsed.unit_numerator, "{text}");
+                    assert_eq!(unit.unit_denominator, parsed.unit_denominator, "{text}");
+                    assert_eq!(unit.value, parsed.value, "{text}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn pop_keeps_unrelated_dimensions() {
+        let unit = kJ!(1.0) / temperature!(1.0, TemperatureDeltaUnit::F);
+        let si = unit.to_si_units();
+        assert_close(1800.0, si.value);
+        assert_eq!("kg·m^2/s^2·K", si.unit_to_string());
+    }
+}
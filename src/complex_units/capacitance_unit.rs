@@ -0,0 +1,126 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::complex_units::ComplexUnit;
+use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
+use crate::units::electric_current_unit::ElectricCurrentUnit;
+use crate::units::length_unit::LengthUnit;
+use crate::units::luminous_intensity_unit::LuminousIntensityUnit;
+use crate::units::mass_unit::MassUnit;
+use crate::units::temperature_unit::TemperatureDeltaUnit;
+use crate::units::time_unit::TimeUnit;
+
+#[macro_export]
+macro_rules! F {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::capacitance_unit::FARAD,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! mF {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::capacitance_unit::MILLIFARAD,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! uF {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::capacitance_unit::MICROFARAD,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! nF {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::capacitance_unit::NANOFARAD,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! pF {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::capacitance_unit::PICOFARAD,
+        )
+    }};
+}
+
+pub const FARAD: ComplexUnit = ComplexUnit {
+    prefix_multiplier: 1.0,
+    amount_of_substance_count: 0,
+    amount_of_substance_unit: AmountOfSubstanceUnit::None,
+    electric_current_count: 2,
+    electric_current_unit: ElectricCurrentUnit::Ampere,
+    length_count: -2,
+    length_unit: LengthUnit::Meter,
+    luminous_intensity_count: 0,
+    luminous_intensity_unit: LuminousIntensityUnit::None,
+    mass_count: -1,
+    mass_unit: MassUnit::Kilogram,
+    temperature_count: 0,
+    temperature_unit: TemperatureDeltaUnit::None,
+    time_count: 4,
+    time_unit: TimeUnit::Second,
+    unit_string: "F",
+};
+pub const MILLIFARAD: ComplexUnit = FARAD.with_prefix(1000.0, "mF");
+pub const MICROFARAD: ComplexUnit = FARAD.with_prefix(1.0e6, "µF");
+pub const NANOFARAD: ComplexUnit = FARAD.with_prefix(1.0e9, "nF");
+pub const PICOFARAD: ComplexUnit = FARAD.with_prefix(1.0e12, "pF");
+
+#[cfg(test)]
+mod tests {
+    use crate::complex_units::capacitance_unit::*;
+    use crate::testing::assert_si_values;
+
+    #[test]
+    fn to_string() {
+        assert_eq!("2.00 F", F!(2.0).to_string());
+        assert_eq!("2.00 mF", mF!(2.0).to_string());
+        assert_eq!("2.00 µF", uF!(2.0).to_string());
+        assert_eq!("2.00 nF", nF!(2.0).to_string());
+        assert_eq!("2.00 pF", pF!(2.0).to_string());
+    }
+
+    #[test]
+    fn to_si_units() {
+        assert_si_values(
+            &[
+                (FARAD, 1.0),
+                (MILLIFARAD, 1.0e-3),
+                (MICROFARAD, 1.0e-6),
+                (NANOFARAD, 1.0e-9),
+                (PICOFARAD, 1.0e-12),
+            ],
+            "A^2·s^4/m^2·kg",
+        );
+    }
+}
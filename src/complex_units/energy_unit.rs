@@ -14,45 +14,206 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use crate::complex_units::force_unit::POUND_FORCE_TO_NEWTONS;
 use crate::complex_units::ComplexUnit;
+use crate::constants::ELEMENTARY_CHARGE;
 use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
 use crate::units::electric_current_unit::ElectricCurrentUnit;
-use crate::units::length_unit::LengthUnit;
+use crate::units::length_unit::{LengthUnit, FOOT_TO_METERS};
 use crate::units::luminous_intensity_unit::LuminousIntensityUnit;
-use crate::units::mass_unit::MassUnit;
+use crate::units::mass_unit::{MassUnit, POUND_TO_KILOGRAMS};
 use crate::units::temperature_unit::TemperatureDeltaUnit;
 use crate::units::time_unit::TimeUnit;
 
 #[macro_export]
 macro_rules! J {
     ($value:expr) => {{
-        let mut unit = EngUnit::new();
-        unit.value = $value;
-        unit.mass_count = 1;
-        unit.length_count = 2;
-        unit.time_count = -2;
-        unit.mass_unit = MassUnit::Kilogram;
-        unit.length_unit = LengthUnit::Meter;
-        unit.time_unit = TimeUnit::Second;
-        push_complex_numerator(&unit, JOULE).unwrap()
+        $crate::complex_units::from_complex_unit($value, $crate::complex_units::energy_unit::JOULE)
     }};
 }
 
 #[macro_export]
 macro_rules! kJ {
     ($value:expr) => {{
-        let mut unit = EngUnit::new();
-        unit.value = $value / KILOJOULE.prefix_multiplier;
-        unit.mass_count = 1;
-        unit.length_count = 2;
-        unit.time_count = -2;
-        unit.mass_unit = MassUnit::Kilogram;
-        unit.length_unit = LengthUnit::Meter;
-        unit.time_unit = TimeUnit::Second;
-        push_complex_numerator(&unit, KILOJOULE).unwrap()
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::KILOJOULE,
+        )
     }};
 }
 
+#[macro_export]
+macro_rules! BTU {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit($value, $crate::complex_units::energy_unit::BTU)
+    }};
+}
+
+#[macro_export]
+macro_rules! erg {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit($value, $crate::complex_units::energy_unit::ERG)
+    }};
+}
+
+#[macro_export]
+macro_rules! MJ {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::MEGAJOULE,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! GJ {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::GIGAJOULE,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! TJ {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::TERAJOULE,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! Wh {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::WATT_HOUR,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! kWh {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::KILOWATT_HOUR,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! MWh {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::MEGAWATT_HOUR,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! BTU_th {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::BTU_THERMOCHEMICAL,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! cal {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::CALORIE,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! kcal {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::KILOCALORIE,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! cal_IT {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::CALORIE_IT,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! kcal_IT {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::KILOCALORIE_IT,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! therm {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit($value, $crate::complex_units::energy_unit::THERM)
+    }};
+}
+
+#[macro_export]
+macro_rules! eV {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::ELECTRONVOLT,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! ft_lbf {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::energy_unit::FOOT_POUND_FORCE,
+        )
+    }};
+}
+
+pub const WATT_HOUR_TO_JOULES: f64 = 3_600.0;
+/// Thermochemical calorie, used in chemistry and for food energy.
+pub const CALORIE_TO_JOULES: f64 = 4.184;
+/// International Steam Table calorie.
+pub const CALORIE_IT_TO_JOULES: f64 = 4.1868;
+/// International Table British thermal unit: an IT calorie per gram per
+/// kelvin, taken per pound per degree Fahrenheit.
+pub const BTU_TO_JOULES: f64 = CALORIE_IT_TO_JOULES * POUND_TO_GRAMS * 5.0 / 9.0;
+/// Thermochemical British thermal unit, defined like [`BTU_TO_JOULES`] from
+/// the thermochemical calorie.
+pub const BTU_THERMOCHEMICAL_TO_JOULES: f64 = CALORIE_TO_JOULES * POUND_TO_GRAMS * 5.0 / 9.0;
+/// 100 000 IT British thermal units (the EC therm; the US therm is 0.02 %
+/// smaller).
+pub const THERM_TO_JOULES: f64 = 1.0e5 * BTU_TO_JOULES;
+/// The energy of one elementary charge across one volt.
+pub const ELECTRONVOLT_TO_JOULES: f64 = ELEMENTARY_CHARGE;
+pub const FOOT_POUND_FORCE_TO_JOULES: f64 = FOOT_TO_METERS * POUND_FORCE_TO_NEWTONS;
+const POUND_TO_GRAMS: f64 = POUND_TO_KILOGRAMS * 1000.0;
+/// CGS unit of energy, 1 dyn·cm.
+pub const ERG_TO_JOULES: f64 = 1.0e-7;
+
 pub const JOULE: ComplexUnit = ComplexUnit {
     prefix_multiplier: 1.0,
     amount_of_substance_count: 0,
@@ -90,15 +251,34 @@ pub const KILOJOULE: ComplexUnit = ComplexUnit {
     time_unit: TimeUnit::Second,
     unit_string: "kJ",
 };
+pub const MEGAJOULE: ComplexUnit = JOULE.with_prefix(1.0e-6, "MJ");
+pub const GIGAJOULE: ComplexUnit = JOULE.with_prefix(1.0e-9, "GJ");
+pub const TERAJOULE: ComplexUnit = JOULE.with_prefix(1.0e-12, "TJ");
+pub const WATT_HOUR: ComplexUnit = JOULE.with_prefix(1.0 / WATT_HOUR_TO_JOULES, "Wh");
+pub const KILOWATT_HOUR: ComplexUnit = JOULE.with_prefix(1.0e-3 / WATT_HOUR_TO_JOULES, "kWh");
+pub const MEGAWATT_HOUR: ComplexUnit = JOULE.with_prefix(1.0e-6 / WATT_HOUR_TO_JOULES, "MWh");
+pub const BTU: ComplexUnit = JOULE.with_prefix(1.0 / BTU_TO_JOULES, "BTU");
+pub const BTU_THERMOCHEMICAL: ComplexUnit =
+    JOULE.with_prefix(1.0 / BTU_THERMOCHEMICAL_TO_JOULES, "BTU_th");
+pub const CALORIE: ComplexUnit = JOULE.with_prefix(1.0 / CALORIE_TO_JOULES, "cal");
+pub const KILOCALORIE: ComplexUnit = JOULE.with_prefix(1.0e-3 / CALORIE_TO_JOULES, "kcal");
+pub const CALORIE_IT: ComplexUnit = JOULE.with_prefix(1.0 / CALORIE_IT_TO_JOULES, "cal_IT");
+pub const KILOCALORIE_IT: ComplexUnit = JOULE.with_prefix(1.0e-3 / CALORIE_IT_TO_JOULES, "kcal_IT");
+pub const THERM: ComplexUnit = JOULE.with_prefix(1.0 / THERM_TO_JOULES, "thm");
+pub const ERG: ComplexUnit = JOULE.with_prefix(1.0 / ERG_TO_JOULES, "erg");
+pub const ELECTRONVOLT: ComplexUnit = JOULE.with_prefix(1.0 / ELECTRONVOLT_TO_JOULES, "eV");
+pub const FOOT_POUND_FORCE: ComplexUnit =
+    JOULE.with_prefix(1.0 / FOOT_POUND_FORCE_TO_JOULES, "ft·lbf");
 
 #[cfg(test)]
-
 mod tests {
     use super::*;
     use crate::complex_units::*;
+    use crate::testing::assert_close;
 
     mod kj {
-        use crate::temperature;
+        use crate::error::EngUnitError;
+        use crate::{mass_kg, temperature};
 
         use super::*;
 
@@ -141,11 +321,111 @@ mod tests {
             assert_eq!("6.00 kJ^2", u3.to_string());
         }
 
+        #[test]
+        fn try_push_incompatible() {
+            let u1 = mass_kg!(2.0);
+            let err = try_push_complex_numerator(&u1, KILOJOULE).unwrap_err();
+            assert_eq!(
+                EngUnitError::IncompatibleComplexUnit {
+                    unit: "kg".to_string(),
+                    complex: "kJ".to_string()
+                },
+                err
+            );
+        }
+
+        #[test]
+        fn try_push_compatible() {
+            let u1 = kJ!(2.0).to_si_units();
+            let u2 = try_push_complex_numerator(&u1, KILOJOULE).unwrap();
+            assert_eq!("2.00 kJ", u2.to_string());
+        }
+
         #[test]
         fn to_foot() {
             let u1 = kJ!(1.0);
             let u2 = u1.convert(LengthUnit::Foot);
-            assert_eq!("6.00 kJ^2", u2.to_string());
+            assert_eq!("1.00 kJ", u2.to_string());
         }
     }
+
+    #[test]
+    fn to_string() {
+        assert_eq!("2.00 MJ", MJ!(2.0).to_string());
+        assert_eq!("2.00 GJ", GJ!(2.0).to_string());
+        assert_eq!("2.00 TJ", TJ!(2.0).to_string());
+        assert_eq!("2.00 Wh", Wh!(2.0).to_string());
+        assert_eq!("2.00 kWh", kWh!(2.0).to_string());
+        assert_eq!("2.00 MWh", MWh!(2.0).to_string());
+        assert_eq!("2.00 BTU", BTU!(2.0).to_string());
+        assert_eq!("2.00 BTU_th", BTU_th!(2.0).to_string());
+        assert_eq!("2.00 cal", cal!(2.0).to_string());
+        assert_eq!("2.00 kcal", kcal!(2.0).to_string());
+        assert_eq!("2.00 cal_IT", cal_IT!(2.0).to_string());
+        assert_eq!("2.00 kcal_IT", kcal_IT!(2.0).to_string());
+        assert_eq!("2.00 thm", therm!(2.0).to_string());
+        assert_eq!("2.00 erg", erg!(2.0).to_string());
+        assert_eq!("2.00 eV", eV!(2.0).to_string());
+        assert_eq!("2.00 ft·lbf", ft_lbf!(2.0).to_string());
+    }
+
+    /// Reference values from NIST SP 811, appendix B.8.
+    #[test]
+    fn reference_values() {
+        let joules = |unit: EngUnit| {
+            let si = unit.to_si_units();
+            assert_eq!("kg·m^2/s^2", si.unit_to_string());
+            si.value
+        };
+        assert_close(1.0e6, joules(MJ!(1.0)));
+        assert_close(1.0e9, joules(GJ!(1.0)));
+        assert_close(3.6e3, joules(Wh!(1.0)));
+        assert_close(3.6e6, joules(kWh!(1.0)));
+        assert_close(3.6e9, joules(MWh!(1.0)));
+        assert_close(1_055.055_852_62, joules(BTU!(1.0)));
+        assert_close(1_054.350_264_488_888_8, joules(BTU_th!(1.0)));
+        assert_close(4.184, joules(cal!(1.0)));
+        assert_close(4_184.0, joules(kcal!(1.0)));
+        assert_close(4.1868, joules(cal_IT!(1.0)));
+        assert_close(4_186.8, joules(kcal_IT!(1.0)));
+        assert_close(105_505_585.262, joules(therm!(1.0)));
+        assert_close(1.0e-7, joules(erg!(1.0)));
+        assert_close(1.602_176_634e-19, joules(eV!(1.0)));
+        assert_close(1.355_817_948_331_400_4, joules(ft_lbf!(1.0)));
+    }
+
+    #[test]
+    fn conversions() {
+        let x = kWh!(1.0).convert_to(&BTU!(1.0)).unwrap();
+        assert_close(3_412.141_633_127_942, x.value);
+        assert_eq!("BTU", x.unit_to_string());
+        let x = kWh!(1.0).convert_to(&MJ!(1.0)).unwrap();
+        assert_close(3.6, x.value);
+        let x = therm!(1.0).convert_to(&kWh!(1.0)).unwrap();
+        assert_close(29.307_107_017_222_222, x.value);
+        let x = BTU!(1.0).convert_to(&ft_lbf!(1.0)).unwrap();
+        assert_close(778.169_262_265_913_5, x.value);
+        let x = kcal!(1.0).convert_to(&kJ!(1.0)).unwrap();
+        assert_close(4.184, x.value);
+        let x = BTU!(1.0).convert_to(&BTU_th!(1.0)).unwrap();
+        assert_close(4.1868 / 4.184, x.value);
+        let x = erg!(1.0).convert_to(&eV!(1.0)).unwrap();
+        assert_close(624_150_907_446.076_2, x.value);
+    }
+
+    #[test]
+    fn parse() {
+        let x: EngUnit = "2 kWh".parse().unwrap();
+        assert_eq!("2.00 kWh", x.to_string());
+        let x: EngUnit = "2 kcal_IT/kg".parse().unwrap();
+        assert_eq!("2.00 kcal_IT/kg", x.to_string());
+        let x: EngUnit = "2 ft·lbf".parse().unwrap();
+        assert_eq!(vec![FOOT_POUND_FORCE], x.unit_numerator);
+        assert_close(2.0 * 1.355_817_948_331_400_4, x.to_si_units().value);
+        let x: EngUnit = "2 ft*lbf/s".parse().unwrap();
+        assert_eq!("2.00 ft·lbf/s", x.to_string());
+        let x: EngUnit = "2 lbf·ft".parse().unwrap();
+        assert_eq!("2.00 lbf·ft", x.to_string());
+        assert_ne!(vec![FOOT_POUND_FORCE], x.unit_numerator);
+    }
 }
@@ -0,0 +1,245 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::complex_units::{from_complex_unit, ComplexUnit};
+pub use crate::constants::STANDARD_GRAVITY;
+use crate::error::EngUnitError;
+use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
+use crate::units::electric_current_unit::ElectricCurrentUnit;
+use crate::units::length_unit::{LengthUnit, FOOT_TO_METERS};
+use crate::units::luminous_intensity_unit::LuminousIntensityUnit;
+use crate::units::mass_unit::{MassUnit, POUND_TO_KILOGRAMS};
+use crate::units::temperature_unit::TemperatureDeltaUnit;
+use crate::units::time_unit::TimeUnit;
+use crate::EngUnit;
+
+#[macro_export]
+macro_rules! N {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit($value, $crate::complex_units::force_unit::NEWTON)
+    }};
+}
+
+#[macro_export]
+macro_rules! mN {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::force_unit::MILLINEWTON,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! kN {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::force_unit::KILONEWTON,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! MN {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::force_unit::MEGANEWTON,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! lbf {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::force_unit::POUND_FORCE,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! dyne {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit($value, $crate::complex_units::force_unit::DYNE)
+    }};
+}
+
+#[macro_export]
+macro_rules! kgf {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::force_unit::KILOGRAM_FORCE,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! pdl {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit($value, $crate::complex_units::force_unit::POUNDAL)
+    }};
+}
+
+/// Newton's law conversion constant, 32.174 lbm·ft/(lbf·s^2): standard
+/// gravity in ft/s^2.
+pub const G_C: f64 = STANDARD_GRAVITY / FOOT_TO_METERS;
+/// The force that accelerates one pound-mass at 1 ft/s^2, 1 lbm·ft/s^2.
+pub const POUNDAL_TO_NEWTONS: f64 = POUND_TO_KILOGRAMS * FOOT_TO_METERS;
+/// The weight of one pound-mass under standard gravity, `G_C` lbm·ft/s^2.
+pub const POUND_FORCE_TO_NEWTONS: f64 = POUNDAL_TO_NEWTONS * G_C;
+/// The weight of one kilogram under standard gravity.
+pub const KILOGRAM_FORCE_TO_NEWTONS: f64 = STANDARD_GRAVITY;
+/// CGS unit of force, 1 g·cm/s^2.
+pub const DYNE_TO_NEWTONS: f64 = 1.0e-5;
+
+pub const NEWTON: ComplexUnit = ComplexUnit {
+    prefix_multiplier: 1.0,
+    amount_of_substance_count: 0,
+    amount_of_substance_unit: AmountOfSubstanceUnit::None,
+    electric_current_count: 0,
+    electric_current_unit: ElectricCurrentUnit::None,
+    length_count: 1,
+    length_unit: LengthUnit::Meter,
+    luminous_intensity_count: 0,
+    luminous_intensity_unit: LuminousIntensityUnit::None,
+    mass_count: 1,
+    mass_unit: MassUnit::Kilogram,
+    temperature_count: 0,
+    temperature_unit: TemperatureDeltaUnit::None,
+    time_count: -2,
+    time_unit: TimeUnit::Second,
+    unit_string: "N",
+};
+pub const MILLINEWTON: ComplexUnit = NEWTON.with_prefix(1000.0, "mN");
+pub const KILONEWTON: ComplexUnit = NEWTON.with_prefix(1.0 / 1000.0, "kN");
+pub const MEGANEWTON: ComplexUnit = NEWTON.with_prefix(1.0e-6, "MN");
+pub const POUND_FORCE: ComplexUnit = NEWTON.with_prefix(1.0 / POUND_FORCE_TO_NEWTONS, "lbf");
+pub const DYNE: ComplexUnit = NEWTON.with_prefix(1.0 / DYNE_TO_NEWTONS, "dyn");
+pub const KILOGRAM_FORCE: ComplexUnit = NEWTON.with_prefix(1.0 / KILOGRAM_FORCE_TO_NEWTONS, "kgf");
+pub const POUNDAL: ComplexUnit = NEWTON.with_prefix(1.0 / POUNDAL_TO_NEWTONS, "pdl");
+
+/// Converts a force to lbf through `g_c`: the force is expressed in
+/// lbm·ft/s^2 and divided by [`G_C`], as in `F = m·a / g_c`.
+///
+/// Returns [`EngUnitError::DimensionMismatch`] if `force` is not a force.
+pub fn to_pound_force(force: &EngUnit) -> Result<EngUnit, EngUnitError> {
+    let mut lbm_ft_per_s2 = EngUnit::new();
+    lbm_ft_per_s2.value = 1.0;
+    lbm_ft_per_s2.mass_count = 1;
+    lbm_ft_per_s2.mass_unit = MassUnit::Pound;
+    lbm_ft_per_s2.length_count = 1;
+    lbm_ft_per_s2.length_unit = LengthUnit::Foot;
+    lbm_ft_per_s2.time_count = -2;
+    lbm_ft_per_s2.time_unit = TimeUnit::Second;
+    let lbm_ft_per_s2 = force.convert_to(&lbm_ft_per_s2)?;
+    Ok(from_complex_unit(lbm_ft_per_s2.value / G_C, POUND_FORCE))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::complex_units::force_unit::*;
+    use crate::testing::{assert_close, assert_si_values};
+    use crate::units::time_unit::TimeUnit;
+    use crate::{length, mass, time, EngUnit};
+
+    #[test]
+    fn to_string() {
+        assert_eq!("2.00 N", N!(2.0).to_string());
+        assert_eq!("2.00 mN", mN!(2.0).to_string());
+        assert_eq!("2.00 kN", kN!(2.0).to_string());
+        assert_eq!("2.00 MN", MN!(2.0).to_string());
+        assert_eq!("2.00 lbf", lbf!(2.0).to_string());
+        assert_eq!("2.00 dyn", dyne!(2.0).to_string());
+        assert_eq!("2.00 kgf", kgf!(2.0).to_string());
+        assert_eq!("2.00 pdl", pdl!(2.0).to_string());
+    }
+
+    #[test]
+    fn to_si_units() {
+        assert_si_values(
+            &[
+                (NEWTON, 1.0),
+                (MILLINEWTON, 1.0e-3),
+                (KILONEWTON, 1.0e3),
+                (MEGANEWTON, 1.0e6),
+            ],
+            "kg·m/s^2",
+        );
+    }
+
+    #[test]
+    fn non_si_units() {
+        let x = lbf!(1.0).to_si_units();
+        assert_close(4.448_221_615_260_5, x.value);
+        assert_eq!("kg·m/s^2", x.unit_to_string());
+        let x = dyne!(1.0e5).to_si_units();
+        assert_close(1.0, x.value);
+        let x = kgf!(1.0).to_si_units();
+        assert_close(9.806_65, x.value);
+        let x = pdl!(1.0).to_si_units();
+        assert_close(0.138_254_954_376, x.value);
+    }
+
+    #[test]
+    fn gravitational_constants() {
+        assert_close(32.174_048_556_430_45, G_C);
+        assert_close(G_C, POUND_FORCE_TO_NEWTONS / POUNDAL_TO_NEWTONS);
+        assert_close(
+            2.204_622_621_848_776,
+            KILOGRAM_FORCE_TO_NEWTONS / POUND_FORCE_TO_NEWTONS,
+        );
+    }
+
+    #[test]
+    fn pound_mass_to_pound_force() {
+        // F = m·a / g_c: 1 lbm at 32.174 ft/s^2 weighs 1 lbf.
+        let m = mass!(1.0, MassUnit::Pound);
+        let a = length!(1.0, LengthUnit::Foot)
+            / (time!(1.0, TimeUnit::Second) * time!(1.0, TimeUnit::Second));
+        let mut f = m * a;
+        f.value = G_C;
+        assert_eq!("lb·ft/s^2", f.unit_to_string());
+        let lbf = to_pound_force(&f).unwrap();
+        assert_eq!("lbf", lbf.unit_to_string());
+        assert_close(1.0, lbf.value);
+        assert_close(1.0, f.convert_to(&lbf!(1.0)).unwrap().value);
+        assert_close(
+            1.0,
+            to_pound_force(&N!(POUND_FORCE_TO_NEWTONS)).unwrap().value,
+        );
+        assert!(to_pound_force(&mass!(1.0, MassUnit::Pound)).is_err());
+
+        let f = pdl!(G_C).convert_to(&lbf!(1.0)).unwrap();
+        assert_close(1.0, f.value);
+        let f = lbf!(1.0).convert_to(&kgf!(1.0)).unwrap();
+        assert_close(POUND_TO_KILOGRAMS, f.value);
+        assert!(lbf!(1.0).convert_to(&mass!(1.0, MassUnit::Pound)).is_err());
+    }
+
+    #[test]
+    fn slug() {
+        // 1 lbf accelerates 1 slug at 1 ft/s^2.
+        let m = mass!(1.0, MassUnit::Slug);
+        let a = length!(1.0, LengthUnit::Foot)
+            / (time!(1.0, TimeUnit::Second) * time!(1.0, TimeUnit::Second));
+        let f = (m * a).convert_to(&lbf!(1.0)).unwrap();
+        assert_close(1.0, f.value);
+    }
+}
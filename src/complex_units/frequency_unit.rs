@@ -0,0 +1,113 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::complex_units::ComplexUnit;
+use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
+use crate::units::electric_current_unit::ElectricCurrentUnit;
+use crate::units::length_unit::LengthUnit;
+use crate::units::luminous_intensity_unit::LuminousIntensityUnit;
+use crate::units::mass_unit::MassUnit;
+use crate::units::temperature_unit::TemperatureDeltaUnit;
+use crate::units::time_unit::TimeUnit;
+
+#[macro_export]
+macro_rules! Hz {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::frequency_unit::HERTZ,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! kHz {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::frequency_unit::KILOHERTZ,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! MHz {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::frequency_unit::MEGAHERTZ,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! GHz {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::frequency_unit::GIGAHERTZ,
+        )
+    }};
+}
+
+pub const HERTZ: ComplexUnit = ComplexUnit {
+    prefix_multiplier: 1.0,
+    amount_of_substance_count: 0,
+    amount_of_substance_unit: AmountOfSubstanceUnit::None,
+    electric_current_count: 0,
+    electric_current_unit: ElectricCurrentUnit::None,
+    length_count: 0,
+    length_unit: LengthUnit::None,
+    luminous_intensity_count: 0,
+    luminous_intensity_unit: LuminousIntensityUnit::None,
+    mass_count: 0,
+    mass_unit: MassUnit::None,
+    temperature_count: 0,
+    temperature_unit: TemperatureDeltaUnit::None,
+    time_count: -1,
+    time_unit: TimeUnit::Second,
+    unit_string: "Hz",
+};
+pub const KILOHERTZ: ComplexUnit = HERTZ.with_prefix(1.0 / 1000.0, "kHz");
+pub const MEGAHERTZ: ComplexUnit = HERTZ.with_prefix(1.0e-6, "MHz");
+pub const GIGAHERTZ: ComplexUnit = HERTZ.with_prefix(1.0e-9, "GHz");
+
+#[cfg(test)]
+mod tests {
+    use crate::complex_units::frequency_unit::*;
+    use crate::testing::assert_si_values;
+
+    #[test]
+    fn to_string() {
+        assert_eq!("2.00 Hz", Hz!(2.0).to_string());
+        assert_eq!("2.00 kHz", kHz!(2.0).to_string());
+        assert_eq!("2.00 MHz", MHz!(2.0).to_string());
+        assert_eq!("2.00 GHz", GHz!(2.0).to_string());
+    }
+
+    #[test]
+    fn to_si_units() {
+        assert_si_values(
+            &[
+                (HERTZ, 1.0),
+                (KILOHERTZ, 1.0e3),
+                (MEGAHERTZ, 1.0e6),
+                (GIGAHERTZ, 1.0e9),
+            ],
+            "/s",
+        );
+    }
+}
@@ -0,0 +1,365 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::complex_units::from_complex_unit;
+use crate::complex_units::pressure_unit::{ATMOSPHERE, BAR, PASCAL, PSI};
+use crate::error::EngUnitError;
+use crate::format::{pad, FormatOptions, Notation};
+use crate::units::{same_dimensions, EngUnit};
+
+use std::fmt::Display;
+use std::ops;
+
+/// Gauge pressure in psi, relative to a standard atmosphere.
+#[macro_export]
+macro_rules! psig {
+    ($value:expr) => {{
+        $crate::complex_units::gauge_pressure::GaugePressure::psig($value)
+    }};
+}
+
+/// Absolute pressure in psi, a plain [`crate::EngUnit`].
+#[macro_export]
+macro_rules! psia {
+    ($value:expr) => {{
+        $crate::psi!($value)
+    }};
+}
+
+/// Gauge pressure in bar, relative to a standard atmosphere.
+#[macro_export]
+macro_rules! barg {
+    ($value:expr) => {{
+        $crate::complex_units::gauge_pressure::GaugePressure::barg($value)
+    }};
+}
+
+/// Absolute pressure in bar, a plain [`crate::EngUnit`].
+#[macro_export]
+macro_rules! bara {
+    ($value:expr) => {{
+        $crate::bar!($value)
+    }};
+}
+
+/// A pressure reading relative to the surrounding atmosphere.
+///
+/// Like [`crate::AbsoluteTemperature`], a gauge reading has an offset zero:
+/// it becomes an absolute pressure only once the atmospheric pressure is
+/// added. Plain [`EngUnit`] pressures are absolute.
+///
+/// Both the reading and the atmosphere are always pressures; they can only
+/// be set through [`GaugePressure::try_new`] and the other constructors,
+/// which check this or build a pressure themselves.
+#[derive(Clone, Debug)]
+pub struct GaugePressure {
+    value: EngUnit,
+    atmosphere: EngUnit,
+}
+
+/// Units written with a `g` suffix for gauge readings, as in `psig`.
+const GAUGE_SUFFIX_UNITS: &[&str] = &["psi", "bar"];
+
+impl Display for GaugePressure {
+    /// `30.00 psig`, `2.00 barg`. Readings in any other unit are followed by
+    /// `gauge` instead: `2.00 kPa gauge`, `100000.00 kg/m·s^2 gauge`.
+    ///
+    /// Formatter flags are honoured as for [`EngUnit`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let notation = if f.alternate() {
+            Notation::Engineering
+        } else {
+            Notation::Fixed
+        };
+        let options = FormatOptions::from_formatter(f, notation);
+        let mut s = self.value.to_string_with(&options);
+        match self.single_named_unit() {
+            Some(symbol) if GAUGE_SUFFIX_UNITS.contains(&symbol) => s.push('g'),
+            _ => s.push_str(" gauge"),
+        }
+        pad(f, &s)
+    }
+}
+
+impl GaugePressure {
+    /// A reading relative to `atmosphere`, e.g. the local barometric
+    /// pressure or [`standard_atmosphere`].
+    pub fn try_new(value: EngUnit, atmosphere: EngUnit) -> Result<Self, EngUnitError> {
+        check_pressure(&value)?;
+        check_pressure(&atmosphere)?;
+        Ok(GaugePressure { value, atmosphere })
+    }
+
+    /// `value` psi above one standard atmosphere.
+    pub fn psig(value: f64) -> Self {
+        GaugePressure {
+            value: from_complex_unit(value, PSI),
+            atmosphere: standard_atmosphere(),
+        }
+    }
+
+    /// `value` bar above one standard atmosphere.
+    pub fn barg(value: f64) -> Self {
+        GaugePressure {
+            value: from_complex_unit(value, BAR),
+            atmosphere: standard_atmosphere(),
+        }
+    }
+
+    /// Gauge reading of an absolute pressure, in the units of `absolute`.
+    pub fn from_absolute(absolute: &EngUnit, atmosphere: EngUnit) -> Result<Self, EngUnitError> {
+        let value = absolute.checked_sub(&atmosphere)?;
+        GaugePressure::try_new(value, atmosphere)
+    }
+
+    /// The reading, e.g. `30 psi` for 30 psig.
+    pub fn value(&self) -> &EngUnit {
+        &self.value
+    }
+
+    /// Atmospheric pressure the reading is relative to.
+    pub fn atmosphere(&self) -> &EngUnit {
+        &self.atmosphere
+    }
+
+    /// The absolute pressure, in the units of the reading.
+    pub fn to_absolute(&self) -> EngUnit {
+        self.value
+            .checked_add(&self.atmosphere)
+            .expect("gauge and atmospheric pressures share a dimension")
+    }
+
+    /// Symbol of the reading's unit if it is a single named unit, e.g. `psi`.
+    fn single_named_unit(&self) -> Option<&'static str> {
+        match self.value.unit_numerator.as_slice() {
+            [complex] => {
+                let mut rest = self.value.clone();
+                rest.unit_numerator.clear();
+                (!rest.has_units()).then_some(complex.unit_string)
+            }
+            _ => None,
+        }
+    }
+
+    /// The same reading in the units of `target`.
+    pub fn convert_to(&self, target: &EngUnit) -> Result<GaugePressure, EngUnitError> {
+        Ok(GaugePressure {
+            value: self.value.convert_to(target)?,
+            atmosphere: self.atmosphere.clone(),
+        })
+    }
+
+    /// The same absolute pressure read against a different atmosphere.
+    pub fn with_atmosphere(&self, atmosphere: EngUnit) -> Result<GaugePressure, EngUnitError> {
+        let mut p = GaugePressure::from_absolute(&self.to_absolute(), atmosphere)?;
+        p.value = p.value.convert_to(&self.value)?;
+        Ok(p)
+    }
+
+    /// Adds a pressure difference, keeping the units and the atmosphere.
+    pub fn checked_add(&self, delta: &EngUnit) -> Result<GaugePressure, EngUnitError> {
+        Ok(GaugePressure {
+            value: self.value.checked_add(delta)?,
+            atmosphere: self.atmosphere.clone(),
+        })
+    }
+
+    /// Subtracts a pressure difference, keeping the units and the
+    /// atmosphere.
+    pub fn checked_sub(&self, delta: &EngUnit) -> Result<GaugePressure, EngUnitError> {
+        Ok(GaugePressure {
+            value: self.value.checked_sub(delta)?,
+            atmosphere: self.atmosphere.clone(),
+        })
+    }
+}
+
+/// One standard atmosphere, the default reference of [`GaugePressure`].
+pub fn standard_atmosphere() -> EngUnit {
+    from_complex_unit(1.0, ATMOSPHERE)
+}
+
+fn check_pressure(unit: &EngUnit) -> Result<(), EngUnitError> {
+    if same_dimensions(unit, &from_complex_unit(1.0, PASCAL)) {
+        Ok(())
+    } else {
+        Err(EngUnitError::DimensionMismatch {
+            left: PASCAL.unit_to_string(),
+            right: unit.unit_to_string(),
+        })
+    }
+}
+
+impl From<GaugePressure> for EngUnit {
+    /// The absolute pressure.
+    fn from(value: GaugePressure) -> Self {
+        value.to_absolute()
+    }
+}
+
+impl ops::Sub for GaugePressure {
+    type Output = EngUnit;
+
+    /// Difference between the absolute pressures, in the units of the
+    /// left-hand side. Readings against different atmospheres compare
+    /// correctly. Panics where [`EngUnit::checked_sub`] would fail, e.g. when
+    /// the conversion overflows.
+    fn sub(self, rhs: Self) -> Self::Output {
+        let delta = rhs
+            .to_absolute()
+            .convert_to(&self.value)
+            .and_then(|rhs| self.to_absolute().checked_sub(&rhs));
+        match delta {
+            Ok(delta) => delta,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl ops::Add<EngUnit> for GaugePressure {
+    type Output = GaugePressure;
+
+    fn add(self, rhs: EngUnit) -> Self::Output {
+        match self.checked_add(&rhs) {
+            Ok(p) => p,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl ops::Sub<EngUnit> for GaugePressure {
+    type Output = GaugePressure;
+
+    fn sub(self, rhs: EngUnit) -> Self::Output {
+        match self.checked_sub(&rhs) {
+            Ok(p) => p,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::complex_units::gauge_pressure::*;
+    use crate::testing::assert_close;
+    use crate::units::length_unit::LengthUnit;
+    use crate::{kPa, length};
+
+    #[test]
+    fn to_string() {
+        assert_eq!("30.00 psig", psig!(30.0).to_string());
+        assert_eq!("2.00 barg", barg!(2.0).to_string());
+        assert_eq!("30.00 psi", psia!(30.0).to_string());
+        assert_eq!("2.00 bar", bara!(2.0).to_string());
+        let gauge = |value| GaugePressure::try_new(value, standard_atmosphere()).unwrap();
+        assert_eq!("2.00 mmHg gauge", gauge(crate::mmHg!(2.0)).to_string());
+        assert_eq!("2.00 kPa gauge", gauge(kPa!(2.0)).to_string());
+        assert_eq!("2.00 atm gauge", gauge(crate::atm!(2.0)).to_string());
+        assert_eq!("2.00 Torr gauge", gauge(crate::Torr!(2.0)).to_string());
+        assert_eq!("2.00 mbar gauge", gauge(crate::mbar!(2.0)).to_string());
+        let p = gauge(kPa!(100.0).to_si_units());
+        assert_eq!("100000.00 kg/m·s^2 gauge", p.to_string());
+    }
+
+    #[test]
+    fn formatter_flags() {
+        assert_eq!("30.1235 psig", format!("{:.4}", psig!(30.123_456)));
+        assert_eq!("        30.00 psig", format!("{:>18}", psig!(30.0)));
+        assert_eq!("30.00 psig~~", format!("{:~<12}", psig!(30.0)));
+    }
+
+    #[test]
+    fn accessors() {
+        let p = GaugePressure::try_new(kPa!(50.0), kPa!(90.0)).unwrap();
+        assert_eq!("50.00 kPa", p.value().to_string());
+        assert_eq!("90.00 kPa", p.atmosphere().to_string());
+    }
+
+    #[test]
+    fn to_absolute() {
+        let p = psig!(0.0).to_absolute();
+        assert_eq!("psi", p.unit_to_string());
+        assert_close(14.695_948_775_513_45, p.value);
+
+        let p = barg!(2.0).to_absolute();
+        assert_close(3.013_25, p.value);
+        let p: EngUnit = barg!(2.0).into();
+        assert_close(3.013_25, p.value);
+    }
+
+    #[test]
+    fn local_atmosphere() {
+        // Denver, about 83.4 kPa.
+        let p = GaugePressure::try_new(crate::psi!(30.0), kPa!(83.4)).unwrap();
+        let absolute = p.to_absolute();
+        assert_close(30.0 + 83.4 / 6.894_757_293_168_361, absolute.value);
+
+        let p = p.with_atmosphere(standard_atmosphere()).unwrap();
+        assert_eq!("psi", p.value().unit_to_string());
+        assert_close(absolute.value - 14.695_948_775_513_45, p.value().value);
+        assert_close(absolute.value, p.to_absolute().value);
+    }
+
+    #[test]
+    fn from_absolute() {
+        let p = GaugePressure::from_absolute(&psia!(44.7), standard_atmosphere()).unwrap();
+        assert_close(44.7 - 14.695_948_775_513_45, p.value().value);
+        assert_eq!("psi", p.value().unit_to_string());
+    }
+
+    #[test]
+    fn convert() {
+        let p = barg!(1.0).convert_to(&crate::psi!(1.0)).unwrap();
+        assert_close(14.503_773_773_020_923, p.value().value);
+        assert_eq!("14.50 psig", p.to_string());
+        assert!(barg!(1.0)
+            .convert_to(&length!(1.0, LengthUnit::Meter))
+            .is_err());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let p = psig!(30.0) + crate::psi!(5.0);
+        assert_close(35.0, p.value().value);
+        let p = p - crate::psi!(10.0);
+        assert_close(25.0, p.value().value);
+
+        let atm = kPa!(90.0);
+        let p1 = GaugePressure::try_new(kPa!(100.0), atm.clone()).unwrap();
+        let p2 = GaugePressure::try_new(kPa!(50.0), kPa!(101.325)).unwrap();
+        let delta = p1 - p2;
+        assert_eq!("kPa", delta.unit_to_string());
+        assert_close(190.0 - 151.325, delta.value);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-finite value")]
+    fn difference_overflows() {
+        let p1 = GaugePressure::try_new(kPa!(1.0), kPa!(100.0)).unwrap();
+        let p2 = GaugePressure::try_new(crate::GPa!(f64::MAX), kPa!(100.0)).unwrap();
+        let _ = p1 - p2;
+    }
+
+    #[test]
+    fn not_a_pressure() {
+        let err = GaugePressure::try_new(length!(1.0, LengthUnit::Meter), standard_atmosphere())
+            .unwrap_err();
+        assert!(matches!(err, EngUnitError::DimensionMismatch { .. }));
+        assert!(psig!(1.0)
+            .checked_add(&length!(1.0, LengthUnit::Meter))
+            .is_err());
+    }
+}
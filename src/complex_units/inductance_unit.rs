@@ -0,0 +1,96 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::complex_units::ComplexUnit;
+use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
+use crate::units::electric_current_unit::ElectricCurrentUnit;
+use crate::units::length_unit::LengthUnit;
+use crate::units::luminous_intensity_unit::LuminousIntensityUnit;
+use crate::units::mass_unit::MassUnit;
+use crate::units::temperature_unit::TemperatureDeltaUnit;
+use crate::units::time_unit::TimeUnit;
+
+#[macro_export]
+macro_rules! H {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::inductance_unit::HENRY,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! mH {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::inductance_unit::MILLIHENRY,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! uH {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::inductance_unit::MICROHENRY,
+        )
+    }};
+}
+
+pub const HENRY: ComplexUnit = ComplexUnit {
+    prefix_multiplier: 1.0,
+    amount_of_substance_count: 0,
+    amount_of_substance_unit: AmountOfSubstanceUnit::None,
+    electric_current_count: -2,
+    electric_current_unit: ElectricCurrentUnit::Ampere,
+    length_count: 2,
+    length_unit: LengthUnit::Meter,
+    luminous_intensity_count: 0,
+    luminous_intensity_unit: LuminousIntensityUnit::None,
+    mass_count: 1,
+    mass_unit: MassUnit::Kilogram,
+    temperature_count: 0,
+    temperature_unit: TemperatureDeltaUnit::None,
+    time_count: -2,
+    time_unit: TimeUnit::Second,
+    unit_string: "H",
+};
+pub const MILLIHENRY: ComplexUnit = HENRY.with_prefix(1000.0, "mH");
+pub const MICROHENRY: ComplexUnit = HENRY.with_prefix(1.0e6, "µH");
+
+#[cfg(test)]
+mod tests {
+    use crate::complex_units::inductance_unit::*;
+    use crate::testing::assert_si_values;
+
+    #[test]
+    fn to_string() {
+        assert_eq!("2.00 H", H!(2.0).to_string());
+        assert_eq!("2.00 mH", mH!(2.0).to_string());
+        assert_eq!("2.00 µH", uH!(2.0).to_string());
+    }
+
+    #[test]
+    fn to_si_units() {
+        assert_si_values(
+            &[(HENRY, 1.0), (MILLIHENRY, 1.0e-3), (MICROHENRY, 1.0e-6)],
+            "kg·m^2/A^2·s^2",
+        );
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::s;
+use crate::complex_units::ComplexUnit;
 use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
 use crate::units::electric_current_unit::ElectricCurrentUnit;
 use crate::units::length_unit::LengthUnit;
@@ -22,45 +22,135 @@ use crate::units::luminous_intensity_unit::LuminousIntensityUnit;
 use crate::units::mass_unit::MassUnit;
 use crate::units::temperature_unit::TemperatureDeltaUnit;
 use crate::units::time_unit::TimeUnit;
-use crate::EngUnit;
-use crate::J;
 
 #[macro_export]
 macro_rules! W {
     ($value:expr) => {{
-        let j = J!($value);
-        let s = s!(1.0);
-        j / s
+        $crate::complex_units::from_complex_unit($value, $crate::complex_units::power_unit::WATT)
+    }};
+}
+
+#[macro_export]
+macro_rules! mW {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::power_unit::MILLIWATT,
+        )
     }};
 }
 
 #[macro_export]
 macro_rules! kW {
     ($value:expr) => {{
-        let j = J!($value * 1000.0);
-        let s = s!(1.0);
-        j / s
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::power_unit::KILOWATT,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! MW {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::power_unit::MEGAWATT,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! GW {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::power_unit::GIGAWATT,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! TW {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::power_unit::TERAWATT,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! hp {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::power_unit::HORSEPOWER,
+        )
     }};
 }
 
+#[macro_export]
+macro_rules! PS {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::power_unit::METRIC_HORSEPOWER,
+        )
+    }};
+}
+
+/// Mechanical (imperial) horsepower, 550 ft·lbf/s.
+pub const HORSEPOWER_TO_WATTS: f64 = 745.699_871_582_270_2;
+/// Metric horsepower (PS), 75 kgf·m/s.
+pub const METRIC_HORSEPOWER_TO_WATTS: f64 = 735.498_75;
+
+pub const WATT: ComplexUnit = ComplexUnit {
+    prefix_multiplier: 1.0,
+    amount_of_substance_count: 0,
+    amount_of_substance_unit: AmountOfSubstanceUnit::None,
+    electric_current_count: 0,
+    electric_current_unit: ElectricCurrentUnit::None,
+    length_count: 2,
+    length_unit: LengthUnit::Meter,
+    luminous_intensity_count: 0,
+    luminous_intensity_unit: LuminousIntensityUnit::None,
+    mass_count: 1,
+    mass_unit: MassUnit::Kilogram,
+    temperature_count: 0,
+    temperature_unit: TemperatureDeltaUnit::None,
+    time_count: -3,
+    time_unit: TimeUnit::Second,
+    unit_string: "W",
+};
+pub const MILLIWATT: ComplexUnit = WATT.with_prefix(1000.0, "mW");
+pub const KILOWATT: ComplexUnit = WATT.with_prefix(1.0 / 1000.0, "kW");
+pub const MEGAWATT: ComplexUnit = WATT.with_prefix(1.0e-6, "MW");
+pub const GIGAWATT: ComplexUnit = WATT.with_prefix(1.0e-9, "GW");
+pub const TERAWATT: ComplexUnit = WATT.with_prefix(1.0e-12, "TW");
+pub const HORSEPOWER: ComplexUnit = WATT.with_prefix(1.0 / HORSEPOWER_TO_WATTS, "hp");
+pub const METRIC_HORSEPOWER: ComplexUnit = WATT.with_prefix(1.0 / METRIC_HORSEPOWER_TO_WATTS, "PS");
+
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::complex_units::power_unit::*;
+    use crate::testing::assert_close;
 
     #[test]
     fn test_1() {
         let x = W!(1.0);
         assert_eq!(1.0, x.value);
         assert!(x.has_units());
-        assert_eq!("1.00 kg·m^2/s^3", x.to_string());
+        assert_eq!("1.00 W", x.to_string());
     }
 
     #[test]
     fn test_2() {
         let x = kW!(1.0);
-        assert_eq!(1000.0, x.value);
+        assert_eq!(1.0, x.value);
         assert!(x.has_units());
-        assert_eq!("1000.00 kg·m^2/s^3", x.to_string());
+        assert_eq!("1.00 kW", x.to_string());
+        assert_eq!(1000.0, x.to_si_units().value);
     }
 
     #[test]
@@ -72,4 +162,36 @@ mod tests {
         assert!(!z.has_units());
         assert_eq!("1000.00", z.to_string());
     }
+
+    #[test]
+    fn to_string() {
+        assert_eq!("2.00 W", W!(2.0).to_string());
+        assert_eq!("2.00 mW", mW!(2.0).to_string());
+        assert_eq!("2.00 kW", kW!(2.0).to_string());
+        assert_eq!("2.00 MW", MW!(2.0).to_string());
+        assert_eq!("2.00 GW", GW!(2.0).to_string());
+        assert_eq!("2.00 TW", TW!(2.0).to_string());
+        assert_eq!("2.00 hp", hp!(2.0).to_string());
+        assert_eq!("2.00 PS", PS!(2.0).to_string());
+    }
+
+    #[test]
+    fn to_si_units() {
+        let x = mW!(2.0).to_si_units();
+        assert_close(0.002, x.value);
+        assert!(x.unit_numerator.is_empty());
+        assert_eq!("kg·m^2/s^3", x.unit_to_string());
+        assert_close(2.0e6, MW!(2.0).to_si_units().value);
+        assert_close(2.0e9, GW!(2.0).to_si_units().value);
+    }
+
+    #[test]
+    fn horsepower() {
+        let x = hp!(1.0).to_si_units();
+        assert_close(745.699_871_582_270_2, x.value);
+        assert_eq!("kg·m^2/s^3", x.unit_to_string());
+        let x = PS!(1.0).to_si_units();
+        assert_close(735.498_75, x.value);
+        assert_eq!(HORSEPOWER.prefix_multiplier, 1.0 / HORSEPOWER_TO_WATTS);
+    }
 }
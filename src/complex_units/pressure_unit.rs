@@ -0,0 +1,249 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::complex_units::force_unit::{POUND_FORCE_TO_NEWTONS, STANDARD_GRAVITY};
+use crate::complex_units::ComplexUnit;
+use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
+use crate::units::electric_current_unit::ElectricCurrentUnit;
+use crate::units::length_unit::{LengthUnit, INCH_TO_METERS};
+use crate::units::luminous_intensity_unit::LuminousIntensityUnit;
+use crate::units::mass_unit::MassUnit;
+use crate::units::temperature_unit::TemperatureDeltaUnit;
+use crate::units::time_unit::TimeUnit;
+
+#[macro_export]
+macro_rules! Pa {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::pressure_unit::PASCAL,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! hPa {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::pressure_unit::HECTOPASCAL,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! kPa {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::pressure_unit::KILOPASCAL,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! MPa {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::pressure_unit::MEGAPASCAL,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! GPa {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::pressure_unit::GIGAPASCAL,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! psi {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit($value, $crate::complex_units::pressure_unit::PSI)
+    }};
+}
+
+#[macro_export]
+macro_rules! Ba {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::pressure_unit::BARYE,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! bar {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit($value, $crate::complex_units::pressure_unit::BAR)
+    }};
+}
+
+#[macro_export]
+macro_rules! mbar {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::pressure_unit::MILLIBAR,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! atm {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::pressure_unit::ATMOSPHERE,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! Torr {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit($value, $crate::complex_units::pressure_unit::TORR)
+    }};
+}
+
+#[macro_export]
+macro_rules! mmHg {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::pressure_unit::MILLIMETER_MERCURY,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! inH2O {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::pressure_unit::INCH_WATER,
+        )
+    }};
+}
+
+/// One pound-force per square inch.
+pub const PSI_TO_PASCALS: f64 = POUND_FORCE_TO_NEWTONS / (INCH_TO_METERS * INCH_TO_METERS);
+pub const BAR_TO_PASCALS: f64 = 1.0e5;
+/// Standard atmosphere, also the default reference for gauge pressures.
+pub const STANDARD_ATMOSPHERE_TO_PASCALS: f64 = 101_325.0;
+/// 1/760 of a standard atmosphere.
+pub const TORR_TO_PASCALS: f64 = STANDARD_ATMOSPHERE_TO_PASCALS / 760.0;
+/// Conventional millimetre of mercury, 13 595.1 kg/m^3 × 1 mm × g0.
+pub const MILLIMETER_MERCURY_TO_PASCALS: f64 = 133.322_387_415;
+/// Conventional inch of water, 1000 kg/m^3 × 1 in × g0.
+pub const INCH_WATER_TO_PASCALS: f64 = 1000.0 * INCH_TO_METERS * STANDARD_GRAVITY;
+/// CGS unit of pressure, 1 dyn/cm^2.
+pub const BARYE_TO_PASCALS: f64 = 0.1;
+
+pub const PASCAL: ComplexUnit = ComplexUnit {
+    prefix_multiplier: 1.0,
+    amount_of_substance_count: 0,
+    amount_of_substance_unit: AmountOfSubstanceUnit::None,
+    electric_current_count: 0,
+    electric_current_unit: ElectricCurrentUnit::None,
+    length_count: -1,
+    length_unit: LengthUnit::Meter,
+    luminous_intensity_count: 0,
+    luminous_intensity_unit: LuminousIntensityUnit::None,
+    mass_count: 1,
+    mass_unit: MassUnit::Kilogram,
+    temperature_count: 0,
+    temperature_unit: TemperatureDeltaUnit::None,
+    time_count: -2,
+    time_unit: TimeUnit::Second,
+    unit_string: "Pa",
+};
+pub const HECTOPASCAL: ComplexUnit = PASCAL.with_prefix(1.0 / 100.0, "hPa");
+pub const KILOPASCAL: ComplexUnit = PASCAL.with_prefix(1.0 / 1000.0, "kPa");
+pub const MEGAPASCAL: ComplexUnit = PASCAL.with_prefix(1.0e-6, "MPa");
+pub const GIGAPASCAL: ComplexUnit = PASCAL.with_prefix(1.0e-9, "GPa");
+pub const PSI: ComplexUnit = PASCAL.with_prefix(1.0 / PSI_TO_PASCALS, "psi");
+pub const BARYE: ComplexUnit = PASCAL.with_prefix(1.0 / BARYE_TO_PASCALS, "Ba");
+pub const BAR: ComplexUnit = PASCAL.with_prefix(1.0 / BAR_TO_PASCALS, "bar");
+pub const MILLIBAR: ComplexUnit = PASCAL.with_prefix(1000.0 / BAR_TO_PASCALS, "mbar");
+pub const ATMOSPHERE: ComplexUnit = PASCAL.with_prefix(1.0 / STANDARD_ATMOSPHERE_TO_PASCALS, "atm");
+pub const TORR: ComplexUnit = PASCAL.with_prefix(1.0 / TORR_TO_PASCALS, "Torr");
+pub const MILLIMETER_MERCURY: ComplexUnit =
+    PASCAL.with_prefix(1.0 / MILLIMETER_MERCURY_TO_PASCALS, "mmHg");
+pub const INCH_WATER: ComplexUnit = PASCAL.with_prefix(1.0 / INCH_WATER_TO_PASCALS, "inH2O");
+
+#[cfg(test)]
+mod tests {
+    use crate::complex_units::pressure_unit::*;
+    use crate::testing::{assert_close, assert_si_values};
+
+    #[test]
+    fn to_string() {
+        assert_eq!("2.00 Pa", Pa!(2.0).to_string());
+        assert_eq!("2.00 hPa", hPa!(2.0).to_string());
+        assert_eq!("2.00 kPa", kPa!(2.0).to_string());
+        assert_eq!("2.00 MPa", MPa!(2.0).to_string());
+        assert_eq!("2.00 GPa", GPa!(2.0).to_string());
+        assert_eq!("2.00 psi", psi!(2.0).to_string());
+        assert_eq!("2.00 Ba", Ba!(2.0).to_string());
+        assert_eq!("2.00 bar", bar!(2.0).to_string());
+        assert_eq!("2.00 mbar", mbar!(2.0).to_string());
+        assert_eq!("2.00 atm", atm!(2.0).to_string());
+        assert_eq!("2.00 Torr", Torr!(2.0).to_string());
+        assert_eq!("2.00 mmHg", mmHg!(2.0).to_string());
+        assert_eq!("2.00 inH2O", inH2O!(2.0).to_string());
+    }
+
+    #[test]
+    fn to_si_units() {
+        assert_si_values(
+            &[
+                (PASCAL, 1.0),
+                (HECTOPASCAL, 1.0e2),
+                (KILOPASCAL, 1.0e3),
+                (MEGAPASCAL, 1.0e6),
+                (GIGAPASCAL, 1.0e9),
+            ],
+            "kg/m·s^2",
+        );
+    }
+
+    #[test]
+    fn reference_values() {
+        assert_close(6_894.757_293_168, psi!(1.0).to_si_units().value);
+        assert_close(100_000.0, bar!(1.0).to_si_units().value);
+        assert_close(100.0, mbar!(1.0).to_si_units().value);
+        assert_close(101_325.0, atm!(1.0).to_si_units().value);
+        assert_close(133.322_368_421, Torr!(1.0).to_si_units().value);
+        assert_close(133.322_387_415, mmHg!(1.0).to_si_units().value);
+        assert_close(249.088_91, inH2O!(1.0).to_si_units().value);
+
+        let p = atm!(1.0).convert_to(&psi!(1.0)).unwrap();
+        assert_close(14.695_948_775_513_45, p.value);
+        let p = atm!(1.0).convert_to(&bar!(1.0)).unwrap();
+        assert_close(1.013_25, p.value);
+        let p = atm!(1.0).convert_to(&Torr!(1.0)).unwrap();
+        assert_close(760.0, p.value);
+        let p = psi!(1.0).convert_to(&inH2O!(1.0)).unwrap();
+        assert_close(27.679_904_710_203, p.value);
+    }
+}
@@ -0,0 +1,87 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::complex_units::ComplexUnit;
+use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
+use crate::units::electric_current_unit::ElectricCurrentUnit;
+use crate::units::length_unit::LengthUnit;
+use crate::units::luminous_intensity_unit::LuminousIntensityUnit;
+use crate::units::mass_unit::MassUnit;
+use crate::units::temperature_unit::TemperatureDeltaUnit;
+use crate::units::time_unit::TimeUnit;
+
+#[macro_export]
+macro_rules! P {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::viscosity_unit::POISE,
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! cP {
+    ($value:expr) => {{
+        $crate::complex_units::from_complex_unit(
+            $value,
+            $crate::complex_units::viscosity_unit::CENTIPOISE,
+        )
+    }};
+}
+
+/// CGS unit of dynamic viscosity, 1 g/cm·s = 0.1 Pa·s.
+pub const POISE: ComplexUnit = ComplexUnit {
+    prefix_multiplier: 10.0,
+    amount_of_substance_count: 0,
+    amount_of_substance_unit: AmountOfSubstanceUnit::None,
+    electric_current_count: 0,
+    electric_current_unit: ElectricCurrentUnit::None,
+    length_count: -1,
+    length_unit: LengthUnit::Meter,
+    luminous_intensity_count: 0,
+    luminous_intensity_unit: LuminousIntensityUnit::None,
+    mass_count: 1,
+    mass_unit: MassUnit::Kilogram,
+    temperature_count: 0,
+    temperature_unit: TemperatureDeltaUnit::None,
+    time_count: -1,
+    time_unit: TimeUnit::Second,
+    unit_string: "P",
+};
+pub const CENTIPOISE: ComplexUnit = POISE.with_prefix(1000.0, "cP");
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::assert_close;
+
+    #[test]
+    fn to_string() {
+        assert_eq!("2.00 P", P!(2.0).to_string());
+        assert_eq!("2.00 cP", cP!(2.0).to_string());
+    }
+
+    #[test]
+    fn to_si_units() {
+        let x = P!(2.0).to_si_units();
+        assert_close(0.2, x.value);
+        assert!(x.unit_numerator.is_empty());
+        assert_eq!("kg/m·s", x.unit_to_string());
+        let x = cP!(1.0).to_si_units();
+        assert_close(0.001, x.value);
+        assert_eq!("kg/m·s", x.unit_to_string());
+    }
+}
@@ -0,0 +1,185 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Physical constants with their units attached, CODATA 2018 values.
+//!
+//! ```
+//! use eng_units::constants::gas_constant;
+//! use eng_units::{kPa, EngUnit};
+//!
+//! let n: EngUnit = "2 mol".parse().unwrap();
+//! let t: EngUnit = "300 K".parse().unwrap();
+//! let v: EngUnit = "0.05 m^3".parse().unwrap();
+//! let p = gas_constant() * n * t / v;
+//! assert_eq!("99.77 kPa", p.convert_to(&kPa!(1.0)).unwrap().to_string());
+//! ```
+
+use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
+use crate::units::length_unit::LengthUnit;
+use crate::units::temperature_unit::TemperatureDeltaUnit;
+use crate::units::time_unit::TimeUnit;
+use crate::{length, temperature, time, EngUnit, C, J, W};
+
+/// Speed of light in vacuum in m/s, exact.
+pub const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+/// Planck constant in J·s, exact.
+pub const PLANCK: f64 = 6.626_070_15e-34;
+/// Elementary charge in C, exact.
+pub const ELEMENTARY_CHARGE: f64 = 1.602_176_634e-19;
+/// Boltzmann constant in J/K, exact.
+pub const BOLTZMANN: f64 = 1.380_649e-23;
+/// Avogadro constant in 1/mol, exact.
+pub const AVOGADRO: f64 = 6.022_140_76e23;
+/// Molar gas constant in J/mol·K, `N_A·k`, exact.
+pub const GAS_CONSTANT: f64 = AVOGADRO * BOLTZMANN;
+/// Faraday constant in C/mol, `N_A·e`, exact.
+pub const FARADAY: f64 = AVOGADRO * ELEMENTARY_CHARGE;
+/// Standard acceleration of gravity in m/s^2, exact by definition.
+pub const STANDARD_GRAVITY: f64 = 9.806_65;
+/// Stefan–Boltzmann constant in W/m^2·K^4. Exact in principle, truncated
+/// here as in CODATA 2018.
+pub const STEFAN_BOLTZMANN: f64 = 5.670_374_419e-8;
+
+/// `value` in 1/mol.
+fn per_mole(value: f64) -> EngUnit {
+    let mut unit = EngUnit::new();
+    unit.value = value;
+    unit.amount_of_substance_count = -1;
+    unit.amount_of_substance_unit = AmountOfSubstanceUnit::Mol;
+    unit
+}
+
+/// Speed of light in vacuum, `c`.
+pub fn speed_of_light() -> EngUnit {
+    let mut unit = length!(1.0, LengthUnit::Meter) / time!(1.0, TimeUnit::Second);
+    unit.value = SPEED_OF_LIGHT;
+    unit
+}
+
+/// Planck constant, `h`.
+pub fn planck() -> EngUnit {
+    J!(PLANCK) * time!(1.0, TimeUnit::Second)
+}
+
+/// Elementary charge, `e`.
+pub fn elementary_charge() -> EngUnit {
+    C!(ELEMENTARY_CHARGE)
+}
+
+/// Boltzmann constant, `k_B`.
+pub fn boltzmann() -> EngUnit {
+    J!(BOLTZMANN) / temperature!(1.0, TemperatureDeltaUnit::K)
+}
+
+/// Avogadro constant, `N_A`.
+pub fn avogadro() -> EngUnit {
+    per_mole(AVOGADRO)
+}
+
+/// Molar gas constant, `R`.
+pub fn gas_constant() -> EngUnit {
+    J!(GAS_CONSTANT) * per_mole(1.0) / temperature!(1.0, TemperatureDeltaUnit::K)
+}
+
+/// Faraday constant, `F`.
+pub fn faraday() -> EngUnit {
+    C!(FARADAY) * per_mole(1.0)
+}
+
+/// Stefan–Boltzmann constant, `σ`.
+pub fn stefan_boltzmann() -> EngUnit {
+    let m = length!(1.0, LengthUnit::Meter);
+    let k = temperature!(1.0, TemperatureDeltaUnit::K);
+    W!(STEFAN_BOLTZMANN) / (m.clone() * m) / (k.clone() * k.clone() * k.clone() * k)
+}
+
+/// Standard acceleration of gravity, `g0`.
+pub fn standard_gravity() -> EngUnit {
+    let s = time!(1.0, TimeUnit::Second);
+    let mut unit = length!(1.0, LengthUnit::Meter) / (s.clone() * s);
+    unit.value = STANDARD_GRAVITY;
+    unit
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::*;
+    use crate::testing::assert_close;
+    use crate::units::same_dimensions;
+    use crate::{kPa, J, N};
+
+    #[test]
+    fn units() {
+        assert_eq!("m/s", speed_of_light().unit_to_string());
+        assert_eq!("J·s", planck().unit_to_string());
+        assert_eq!("C", elementary_charge().unit_to_string());
+        assert_eq!("J/K", boltzmann().unit_to_string());
+        assert_eq!("/mol", avogadro().unit_to_string());
+        assert_eq!("J/mol·K", gas_constant().unit_to_string());
+        assert_eq!("C/mol", faraday().unit_to_string());
+        assert_eq!("W/m^2·K^4", stefan_boltzmann().unit_to_string());
+        assert_eq!("m/s^2", standard_gravity().unit_to_string());
+    }
+
+    #[test]
+    fn derived_values() {
+        assert_close(8.314_462_618_153_24, GAS_CONSTANT);
+        assert_close(96_485.332_123_310_02, FARADAY);
+        assert_close(GAS_CONSTANT, (avogadro() * boltzmann()).value);
+        assert_close(FARADAY, (avogadro() * elementary_charge()).value);
+        assert!(same_dimensions(
+            &gas_constant(),
+            &(avogadro() * boltzmann())
+        ));
+    }
+
+    #[test]
+    fn ideal_gas() {
+        let n: EngUnit = "2 mol".parse().unwrap();
+        let t: EngUnit = "300 K".parse().unwrap();
+        let v: EngUnit = "0.05 m^3".parse().unwrap();
+        let p = gas_constant() * n * t / v;
+        assert_eq!("J/m^3", p.unit_to_string());
+        let p = p.convert_to(&kPa!(1.0)).unwrap();
+        assert_close(2.0 * GAS_CONSTANT * 300.0 / 0.05 / 1000.0, p.value);
+        assert_eq!("99.77 kPa", p.to_string());
+    }
+
+    #[test]
+    fn weight() {
+        let m: EngUnit = "10 kg".parse().unwrap();
+        let w = (m * standard_gravity()).convert_to(&N!(1.0)).unwrap();
+        assert_close(98.066_5, w.value);
+    }
+
+    #[test]
+    fn photon_energy() {
+        // E = h·c / λ for 500 nm.
+        let wavelength: EngUnit = "500 nm".parse().unwrap();
+        let e = planck() * speed_of_light() / wavelength;
+        let e = e.convert_to(&J!(1.0)).unwrap();
+        assert_close(3.972_891_714_297_857e-19, e.value);
+    }
+
+    #[test]
+    fn radiation() {
+        // σ·T^4 for a black body at 1000 K.
+        let t: EngUnit = "1000 K".parse().unwrap();
+        let q = stefan_boltzmann() * t.clone() * t.clone() * t.clone() * t;
+        assert_eq!("W/m^2", q.unit_to_string());
+        assert_close(56_703.744_19, q.value);
+    }
+}
@@ -0,0 +1,92 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::parser::ParseUnitError;
+
+use std::fmt::Display;
+
+/// Error type shared by every fallible operation in the crate.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EngUnitError {
+    /// The two operands do not have the same dimension, e.g. `kg` and `m`.
+    DimensionMismatch { left: String, right: String },
+    /// A unit symbol is not known to the crate.
+    UnknownUnit(String),
+    /// A unit expression could not be parsed.
+    Parse(ParseUnitError),
+    /// The named unit cannot be taken out of, or applied to, the unit.
+    IncompatibleComplexUnit { unit: String, complex: String },
+    /// A calculation produced `NaN` or an infinite value.
+    NonFiniteValue(f64),
+    /// An absolute temperature on an offset scale (°C, °F) was used where
+    /// only a thermodynamic temperature makes sense.
+    OffsetTemperature(String),
+    /// A unit with this symbol already exists.
+    DuplicateUnit(String),
+    /// The symbol cannot be used for a new unit because it is not an
+    /// identifier, e.g. it is empty or contains `/`.
+    InvalidSymbol(String),
+    /// The scale of a new unit is zero, negative or not finite.
+    InvalidScale(f64),
+}
+
+impl Display for EngUnitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EngUnitError::DimensionMismatch { left, right } => {
+                write!(f, "dimension mismatch between '{left}' and '{right}'")
+            }
+            EngUnitError::UnknownUnit(s) => write!(f, "unknown unit '{s}'"),
+            EngUnitError::Parse(e) => e.fmt(f),
+            EngUnitError::IncompatibleComplexUnit { unit, complex } => {
+                write!(f, "'{complex}' is not compatible with '{unit}'")
+            }
+            EngUnitError::NonFiniteValue(value) => write!(f, "non-finite value {value}"),
+            EngUnitError::OffsetTemperature(unit) => {
+                write!(f, "absolute temperature in '{unit}' has an offset zero")
+            }
+            EngUnitError::DuplicateUnit(s) => write!(f, "unit '{s}' is already defined"),
+            EngUnitError::InvalidSymbol(s) => write!(f, "invalid unit symbol '{s}'"),
+            EngUnitError::InvalidScale(scale) => {
+                write!(f, "scale {scale} of a unit must be positive")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EngUnitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EngUnitError::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseUnitError> for EngUnitError {
+    fn from(value: ParseUnitError) -> Self {
+        EngUnitError::Parse(value)
+    }
+}
+
+/// Returns [`EngUnitError::NonFiniteValue`] if `value` is `NaN` or infinite.
+pub(crate) fn check_finite(value: f64) -> Result<f64, EngUnitError> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(EngUnitError::NonFiniteValue(value))
+    }
+}
@@ -0,0 +1,230 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prefix::SiPrefix;
+
+use std::fmt::{Alignment, Formatter};
+
+pub const DEFAULT_PRECISION: usize = 2;
+
+/// How the numeric part of a unit is written.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Notation {
+    /// `1234.50`
+    Fixed,
+    /// `1.23e3`
+    Scientific,
+    /// `1.23e3`, `12.35e3`, `123.45e3`: the exponent is a multiple of three.
+    Engineering,
+    /// Rounded to the given number of significant figures, e.g. `1230` for 3.
+    SignificantFigures(usize),
+}
+
+/// Order of the unit symbols in text output.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum UnitOrder {
+    /// Named units in catalog order, then base units in a fixed order, so
+    /// the same unit always prints the same way.
+    Canonical,
+    /// Named units and base units in the order they were written or
+    /// multiplied in.
+    AsWritten,
+}
+
+/// Options for [`crate::EngUnit::to_string_with`], e.g.
+/// `FormatOptions::new().precision(3).notation(Notation::Engineering)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormatOptions {
+    pub(crate) precision: usize,
+    pub(crate) notation: Notation,
+    pub(crate) sign_plus: bool,
+    pub(crate) prefixes: Option<Vec<SiPrefix>>,
+    pub(crate) ascii: bool,
+    pub(crate) unit_order: UnitOrder,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormatOptions {
+    pub fn new() -> Self {
+        FormatOptions {
+            precision: DEFAULT_PRECISION,
+            notation: Notation::Fixed,
+            sign_plus: false,
+            prefixes: None,
+            ascii: false,
+            unit_order: UnitOrder::Canonical,
+        }
+    }
+
+    /// Digits after the decimal point. Ignored for
+    /// [`Notation::SignificantFigures`].
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Always print a sign, including `+` for positive values.
+    pub fn sign_plus(mut self, sign_plus: bool) -> Self {
+        self.sign_plus = sign_plus;
+        self
+    }
+
+    /// Pick an SI prefix from `allowed` before formatting, see
+    /// [`crate::EngUnit::with_auto_prefix`].
+    pub fn auto_prefix(mut self, allowed: &[SiPrefix]) -> Self {
+        self.prefixes = Some(allowed.to_vec());
+        self
+    }
+
+    /// Write units in ASCII, see [`crate::EngUnit::unit_to_ascii_string`].
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    pub fn unit_order(mut self, unit_order: UnitOrder) -> Self {
+        self.unit_order = unit_order;
+        self
+    }
+
+    /// Options equivalent to the flags of a `{}` formatter.
+    pub(crate) fn from_formatter(f: &Formatter<'_>, notation: Notation) -> Self {
+        FormatOptions::new()
+            .precision(f.precision().unwrap_or(DEFAULT_PRECISION))
+            .notation(notation)
+            .sign_plus(f.sign_plus())
+    }
+
+    /// Writes `value` according to these options.
+    pub fn format_value(&self, value: f64) -> String {
+        let s = match self.notation {
+            Notation::Fixed => format!("{:.*}", self.precision, value),
+            Notation::Scientific => format!("{:.*e}", self.precision, value),
+            Notation::Engineering => engineering(value, self.precision),
+            Notation::SignificantFigures(figures) => significant_figures(value, figures),
+        };
+        if self.sign_plus && !s.starts_with('-') {
+            format!("+{s}")
+        } else {
+            s
+        }
+    }
+}
+
+fn engineering(value: f64, precision: usize) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return format!("{:.*}e0", precision, value);
+    }
+    let mut exponent = (value.abs().log10() / 3.0).floor() as i32 * 3;
+    let mut mantissa = value / 10f64.powi(exponent);
+    // Rounding can carry the mantissa up to 1000, e.g. 999.999 at two places.
+    if format!("{:.*}", precision, mantissa.abs()).parse::<f64>() == Ok(1000.0) {
+        exponent += 3;
+        mantissa = value / 10f64.powi(exponent);
+    }
+    format!("{:.*}e{}", precision, mantissa, exponent)
+}
+
+fn significant_figures(value: f64, figures: usize) -> String {
+    let figures = figures.max(1) as i32;
+    if value == 0.0 || !value.is_finite() {
+        return format!("{:.*}", (figures - 1) as usize, value);
+    }
+    let magnitude = value.abs().log10().floor() as i32;
+    let scale = 10f64.powi(magnitude - figures + 1);
+    let rounded = (value / scale).round() * scale;
+    // Rounding may add a digit, e.g. 9.996 to three figures is 10.0.
+    let magnitude = rounded.abs().log10().floor() as i32;
+    let decimals = figures - 1 - magnitude;
+    if decimals >= 0 {
+        format!("{:.*}", decimals as usize, rounded)
+    } else {
+        format!("{:.0}", rounded)
+    }
+}
+
+/// Writes `s` honouring the width, fill and alignment of `f`. Numbers are
+/// right-aligned by default.
+pub(crate) fn pad(f: &mut Formatter<'_>, s: &str) -> std::fmt::Result {
+    let width = f.width().unwrap_or(0);
+    let len = s.chars().count();
+    if len >= width {
+        return f.write_str(s);
+    }
+    let padding = width - len;
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill().to_string();
+    write!(f, "{}{}{}", fill.repeat(before), s, fill.repeat(after))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::*;
+
+    #[test]
+    fn fixed() {
+        let options = FormatOptions::new();
+        assert_eq!("1.23", options.format_value(1.23456));
+        let options = options.precision(4);
+        assert_eq!("1.2346", options.format_value(1.23456));
+        let options = options.sign_plus(true);
+        assert_eq!("+1.2346", options.format_value(1.23456));
+        assert_eq!("-1.2346", options.format_value(-1.23456));
+    }
+
+    #[test]
+    fn scientific() {
+        let options = FormatOptions::new().notation(Notation::Scientific);
+        assert_eq!("3.00e-9", options.format_value(3e-9));
+        assert_eq!("1.23e4", options.format_value(12345.0));
+    }
+
+    #[test]
+    fn engineering() {
+        let options = FormatOptions::new().notation(Notation::Engineering);
+        assert_eq!("3.00e-9", options.format_value(3e-9));
+        assert_eq!("47.00e-6", options.format_value(4.7e-5));
+        assert_eq!("12.35e3", options.format_value(12345.0));
+        assert_eq!("-123.00e0", options.format_value(-123.0));
+        assert_eq!("1.00e3", options.format_value(999.999));
+        assert_eq!("0.00e0", options.format_value(0.0));
+    }
+
+    #[test]
+    fn significant_figures() {
+        let options = FormatOptions::new().notation(Notation::SignificantFigures(3));
+        assert_eq!("1.23", options.format_value(1.23456));
+        assert_eq!("0.00000300", options.format_value(3e-6));
+        assert_eq!("12300", options.format_value(12345.0));
+        assert_eq!("-0.0123", options.format_value(-0.012345));
+        assert_eq!("0.00", options.format_value(0.0));
+        assert_eq!("10.0", options.format_value(9.996));
+    }
+}
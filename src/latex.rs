@@ -0,0 +1,424 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::format::{FormatOptions, UnitOrder};
+use crate::prefix::ALL_PREFIXES;
+use crate::units::UnitTerm;
+use crate::EngUnit;
+
+/// Layout used by [`EngUnit::to_latex_with`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LatexStyle {
+    /// `$9.81\ \mathrm{kg}\cdot\mathrm{m}/\mathrm{s}^{2}$`, the output of
+    /// [`EngUnit::to_latex`].
+    Inline,
+    /// `$9.81\ \frac{\mathrm{kg}\cdot\mathrm{m}}{\mathrm{s}^{2}}$`
+    Fraction,
+    /// `$9.81\ \mathrm{kg}\cdot\mathrm{m}\cdot\mathrm{s}^{-2}$`
+    NegativePowers,
+    /// `\qty{9.81}{\kilogram\metre\per\second\squared}` for the `siunitx`
+    /// package.
+    Siunitx,
+}
+
+/// `siunitx` macros for unprefixed symbols and symbols that have a macro
+/// of their own.
+const SIUNITX_UNITS: &[(&str, &str)] = &[
+    ("kg", "\\kilogram"),
+    ("g", "\\gram"),
+    ("t", "\\tonne"),
+    ("m", "\\metre"),
+    ("s", "\\second"),
+    ("min", "\\minute"),
+    ("hr", "\\hour"),
+    ("d", "\\day"),
+    ("A", "\\ampere"),
+    ("K", "\\kelvin"),
+    ("°C", "\\degreeCelsius"),
+    ("mol", "\\mole"),
+    ("cd", "\\candela"),
+    ("J", "\\joule"),
+    ("W", "\\watt"),
+    ("N", "\\newton"),
+    ("Pa", "\\pascal"),
+    ("Hz", "\\hertz"),
+    ("C", "\\coulomb"),
+    ("V", "\\volt"),
+    ("Ω", "\\ohm"),
+    ("F", "\\farad"),
+    ("H", "\\henry"),
+    ("T", "\\tesla"),
+    ("Wb", "\\weber"),
+    ("eV", "\\electronvolt"),
+    ("bar", "\\bar"),
+    ("mmHg", "\\mmHg"),
+    ("ft·lbf", "\\text{ft}\\,\\text{lbf}"),
+];
+
+impl EngUnit {
+    pub fn to_latex(&self) -> String {
+        self.to_latex_with(LatexStyle::Inline)
+    }
+
+    /// LaTeX in `style`, with the value written in full.
+    pub fn to_latex_with(&self, style: LatexStyle) -> String {
+        self.latex(style, &self.value.to_string(), UnitOrder::Canonical)
+    }
+
+    /// LaTeX in `style`, with the value, prefix and unit order taken from
+    /// `options`. Exponents of scientific and engineering notation are
+    /// written as `\times 10^{n}`, except for `siunitx`, which reads them.
+    pub fn to_latex_with_options(&self, style: LatexStyle, options: &FormatOptions) -> String {
+        let (unit, value) = self.formatted_parts(options);
+        let value = match style {
+            LatexStyle::Siunitx => value,
+            _ => latex_number(&value),
+        };
+        unit.latex(style, &value, options.unit_order)
+    }
+
+    fn latex(&self, style: LatexStyle, value: &str, order: UnitOrder) -> String {
+        let (numerator, denominator) = self.unit_terms(order);
+        if numerator.is_empty() && denominator.is_empty() {
+            return match style {
+                LatexStyle::Siunitx => format!("\\num{{{value}}}"),
+                _ => format!("${value}$"),
+            };
+        }
+
+        let units = match style {
+            LatexStyle::Siunitx => {
+                return format!(
+                    "\\qty{{{value}}}{{{}}}",
+                    siunitx_units(&numerator, &denominator)
+                );
+            }
+            LatexStyle::Inline => {
+                let mut s = join(&numerator, 1);
+                if !denominator.is_empty() {
+                    s.push('/');
+                    s.push_str(&join(&denominator, 1));
+                }
+                s
+            }
+            LatexStyle::Fraction => {
+                if denominator.is_empty() {
+                    join(&numerator, 1)
+                } else if numerator.is_empty() {
+                    format!("\\frac{{1}}{{{}}}", join(&denominator, 1))
+                } else {
+                    format!(
+                        "\\frac{{{}}}{{{}}}",
+                        join(&numerator, 1),
+                        join(&denominator, 1)
+                    )
+                }
+            }
+            LatexStyle::NegativePowers => {
+                let mut terms = vec![join(&numerator, 1)];
+                terms.push(join(&denominator, -1));
+                terms.retain(|s| !s.is_empty());
+                terms.join("\\cdot ")
+            }
+        };
+        format!("${value}\\ {units}$")
+    }
+}
+
+/// `1.23e4` as `1.23\times 10^{4}`; other numbers are unchanged.
+fn latex_number(value: &str) -> String {
+    match value.split_once('e') {
+        Some((mantissa, exponent)) => format!("{mantissa}\\times 10^{{{exponent}}}"),
+        None => value.to_string(),
+    }
+}
+
+/// Joins terms with `\cdot`, multiplying every exponent by `sign`.
+fn join(terms: &[UnitTerm], sign: i32) -> String {
+    terms
+        .iter()
+        .map(|term| {
+            let symbol = latex_symbol(term.symbol);
+            let exponent = term.exponent * sign;
+            if exponent == 1 {
+                symbol
+            } else {
+                format!("{symbol}^{{{exponent}}}")
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\\cdot ")
+}
+
+/// LaTeX for a single unit symbol, upright in `\mathrm{}`. `µ`, `Ω` and
+/// `°` become `\mu`, `\Omega` and `\circ`; qualifiers such as `_th` become
+/// subscripts and symbols such as `ft·lbf` are written as a product.
+pub(crate) fn latex_symbol(symbol: &str) -> String {
+    if symbol.contains('·') {
+        return symbol
+            .split('·')
+            .map(latex_symbol)
+            .collect::<Vec<String>>()
+            .join("\\cdot ");
+    }
+    if let Some((base, qualifier)) = symbol.split_once('_') {
+        return format!("{}_{{\\mathrm{{{qualifier}}}}}", latex_symbol(base));
+    }
+    if let Some(rest) = symbol.strip_prefix('°') {
+        return format!("{{}}^{{\\circ}}\\mathrm{{{rest}}}");
+    }
+    let (greek, rest) = match symbol.strip_prefix('µ') {
+        Some(rest) => ("\\mu", rest),
+        None => ("", symbol),
+    };
+    let (rest, omega) = match rest.strip_suffix('Ω') {
+        Some(rest) => (rest, "\\Omega"),
+        None => (rest, ""),
+    };
+    if rest.is_empty() {
+        format!("{greek}{omega}")
+    } else {
+        format!("{greek}\\mathrm{{{rest}}}{omega}")
+    }
+}
+
+fn siunitx_units(numerator: &[UnitTerm], denominator: &[UnitTerm]) -> String {
+    let mut s = String::new();
+    for term in numerator {
+        s.push_str(&siunitx_symbol(term.symbol));
+        s.push_str(&siunitx_power(term.exponent));
+    }
+    for term in denominator {
+        s.push_str("\\per");
+        s.push_str(&siunitx_symbol(term.symbol));
+        s.push_str(&siunitx_power(term.exponent));
+    }
+    s
+}
+
+/// `siunitx` macros for a symbol, splitting off an SI prefix where needed.
+/// Symbols without a macro are written as text.
+fn siunitx_symbol(symbol: &str) -> String {
+    let find = |symbol: &str| {
+        SIUNITX_UNITS
+            .iter()
+            .find(|(s, _)| *s == symbol)
+            .map(|(_, macro_name)| *macro_name)
+    };
+    if let Some(macro_name) = find(symbol) {
+        return macro_name.to_string();
+    }
+    for prefix in ALL_PREFIXES {
+        let prefix_symbol = prefix.to_string();
+        if prefix_symbol.is_empty() {
+            continue;
+        }
+        if let Some(macro_name) = symbol.strip_prefix(prefix_symbol).and_then(find) {
+            return format!("\\{}{}", format!("{prefix:?}").to_lowercase(), macro_name);
+        }
+    }
+    format!("\\text{{{}}}", symbol.replace('_', "\\_"))
+}
+
+fn siunitx_power(exponent: i32) -> String {
+    match exponent {
+        1 => String::new(),
+        2 => "\\squared".to_string(),
+        3 => "\\cubed".to_string(),
+        n => format!("\\tothe{{{n}}}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::{FormatOptions, Notation};
+    use crate::latex::*;
+    use crate::prefix::ENGINEERING_PREFIXES;
+    use crate::units::length_unit::LengthUnit;
+    use crate::units::mass_unit::MassUnit;
+    use crate::units::temperature_unit::TemperatureDeltaUnit;
+    use crate::units::time_unit::TimeUnit;
+    use crate::{kJ, length, mass, temperature, time, uF, EngUnit, Mohm, W};
+
+    fn force() -> EngUnit {
+        let m = mass!(2.5, MassUnit::Kilogram);
+        let l = length!(1.0, LengthUnit::Meter);
+        let t = time!(1.0, TimeUnit::Second);
+        m * l / (t.clone() * t)
+    }
+
+    #[test]
+    fn inline() {
+        let unit = force();
+        assert_eq!(
+            "$2.5\\ \\mathrm{kg}\\cdot \\mathrm{m}/\\mathrm{s}^{2}$",
+            unit.to_latex()
+        );
+        let unit = length!(3.0, LengthUnit::Micrometer);
+        assert_eq!("$3\\ \\mu\\mathrm{m}$", unit.to_latex());
+    }
+
+    #[test]
+    fn every_dimension() {
+        let mut unit = force();
+        unit.electric_current_count = -1;
+        unit.electric_current_unit = crate::ElectricCurrentUnit::Ampere;
+        unit.amount_of_substance_count = 1;
+        unit.amount_of_substance_unit = crate::AmountOfSubstanceUnit::Mol;
+        unit.luminous_intensity_count = 2;
+        unit.luminous_intensity_unit = crate::LuminousIntensityUnit::Candela;
+        assert_eq!(
+            "$2.5\\ \\mathrm{mol}\\cdot \\mathrm{kg}\\cdot \\mathrm{m}\\cdot \\mathrm{cd}^{2}/\\mathrm{A}\\cdot \\mathrm{s}^{2}$",
+            unit.to_latex()
+        );
+    }
+
+    #[test]
+    fn fraction() {
+        let unit = force();
+        assert_eq!(
+            "$2.5\\ \\frac{\\mathrm{kg}\\cdot \\mathrm{m}}{\\mathrm{s}^{2}}$",
+            unit.to_latex_with(LatexStyle::Fraction)
+        );
+        let unit = time!(1.0, TimeUnit::Second).reciprocal();
+        assert_eq!(
+            "$1\\ \\frac{1}{\\mathrm{s}}$",
+            unit.to_latex_with(LatexStyle::Fraction)
+        );
+    }
+
+    #[test]
+    fn negative_powers() {
+        let unit = force();
+        assert_eq!(
+            "$2.5\\ \\mathrm{kg}\\cdot \\mathrm{m}\\cdot \\mathrm{s}^{-2}$",
+            unit.to_latex_with(LatexStyle::NegativePowers)
+        );
+    }
+
+    #[test]
+    fn complex_units() {
+        let cp =
+            kJ!(4.2) / mass!(1.0, MassUnit::Kilogram) / temperature!(1.0, TemperatureDeltaUnit::C);
+        assert_eq!(
+            "$4.2\\ \\mathrm{kJ}/\\mathrm{kg}\\cdot {}^{\\circ}\\mathrm{C}$",
+            cp.to_latex()
+        );
+        assert_eq!(
+            "$4.2\\ \\frac{\\mathrm{kJ}}{\\mathrm{kg}\\cdot {}^{\\circ}\\mathrm{C}}$",
+            cp.to_latex_with(LatexStyle::Fraction)
+        );
+        assert_eq!(
+            "$4.7\\ \\mu\\mathrm{F}$",
+            uF!(4.7).to_latex_with(LatexStyle::Fraction)
+        );
+        assert_eq!("$2\\ \\mathrm{M}\\Omega$", Mohm!(2.0).to_latex());
+        assert_eq!(
+            "$2\\ \\mathrm{M}\\Omega$",
+            Mohm!(2.0).to_latex_with(LatexStyle::Fraction)
+        );
+        assert_eq!(
+            "$2\\ \\mathrm{BTU}_{\\mathrm{th}}$",
+            crate::BTU_th!(2.0).to_latex()
+        );
+        assert_eq!(
+            "$2\\ \\mathrm{ft}\\cdot \\mathrm{lbf}$",
+            crate::ft_lbf!(2.0).to_latex_with(LatexStyle::Fraction)
+        );
+    }
+
+    #[test]
+    fn siunitx() {
+        let unit = kJ!(12.3) / mass!(1.0, MassUnit::Kilogram);
+        assert_eq!(
+            "\\qty{12.3}{\\kilo\\joule\\per\\kilogram}",
+            unit.to_latex_with(LatexStyle::Siunitx)
+        );
+        let unit = force();
+        assert_eq!(
+            "\\qty{2.5}{\\kilogram\\metre\\per\\second\\squared}",
+            unit.to_latex_with(LatexStyle::Siunitx)
+        );
+        assert_eq!(
+            "\\qty{4.7}{\\micro\\farad}",
+            uF!(4.7).to_latex_with(LatexStyle::Siunitx)
+        );
+        let unit = length!(2.0, LengthUnit::Foot);
+        assert_eq!(
+            "\\qty{2}{\\text{ft}}",
+            unit.to_latex_with(LatexStyle::Siunitx)
+        );
+        assert_eq!(
+            "\\qty{2}{\\text{BTU\\_th}}",
+            crate::BTU_th!(2.0).to_latex_with(LatexStyle::Siunitx)
+        );
+        assert_eq!(
+            "\\qty{2}{\\text{ft}\\,\\text{lbf}}",
+            crate::ft_lbf!(2.0).to_latex_with(LatexStyle::Siunitx)
+        );
+        assert_eq!(
+            "\\qty{3}{\\electronvolt}",
+            crate::eV!(3.0).to_latex_with(LatexStyle::Siunitx)
+        );
+        assert_eq!(
+            "\\qty{3}{\\milli\\bar}",
+            crate::mbar!(3.0).to_latex_with(LatexStyle::Siunitx)
+        );
+        assert_eq!(
+            "\\num{2}",
+            EngUnit {
+                value: 2.0,
+                ..EngUnit::new()
+            }
+            .to_latex_with(LatexStyle::Siunitx)
+        );
+    }
+
+    #[test]
+    fn format_options() {
+        let unit = W!(1.234567);
+        assert_eq!("$1.234567\\ \\mathrm{W}$", unit.to_latex());
+        let options = FormatOptions::new();
+        assert_eq!(
+            "$1.23\\ \\mathrm{W}$",
+            unit.to_latex_with_options(LatexStyle::Inline, &options)
+        );
+        let options = FormatOptions::new()
+            .precision(1)
+            .notation(Notation::Scientific);
+        assert_eq!(
+            "$1.2\\times 10^{3}\\ \\mathrm{W}$",
+            W!(1234.0).to_latex_with_options(LatexStyle::Inline, &options)
+        );
+        assert_eq!(
+            "\\qty{1.2e3}{\\watt}",
+            W!(1234.0).to_latex_with_options(LatexStyle::Siunitx, &options)
+        );
+        let options = FormatOptions::new().auto_prefix(ENGINEERING_PREFIXES);
+        assert_eq!(
+            "$4.70\\ \\mu\\mathrm{F}$",
+            uF!(4.7).to_latex_with_options(LatexStyle::Fraction, &options)
+        );
+        assert_eq!(
+            "\\num{2.50}",
+            EngUnit {
+                value: 2.5,
+                ..EngUnit::new()
+            }
+            .to_latex_with_options(LatexStyle::Siunitx, &FormatOptions::new())
+        );
+    }
+}
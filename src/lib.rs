@@ -15,6 +15,7 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 pub mod units;
+pub use units::absolute_temperature_unit::{AbsoluteTemperature, AbsoluteTemperatureUnit};
 pub use units::amount_of_substance_unit::AmountOfSubstanceUnit;
 pub use units::electric_current_unit::ElectricCurrentUnit;
 pub use units::length_unit::LengthUnit;
@@ -22,5 +23,28 @@ pub use units::luminous_intensity_unit::LuminousIntensityUnit;
 pub use units::mass_unit::MassUnit;
 pub use units::temperature_unit::TemperatureDeltaUnit;
 pub use units::time_unit::TimeUnit;
-pub use units::EngUnit;
+pub use units::{BaseDimension, EngUnit};
 pub mod complex_units;
+pub use complex_units::gauge_pressure::GaugePressure;
+pub mod constants;
+pub mod error;
+pub use error::EngUnitError;
+pub mod format;
+pub use format::{FormatOptions, Notation, UnitOrder};
+pub mod prefix;
+pub use prefix::SiPrefix;
+pub mod latex;
+pub use latex::LatexStyle;
+pub mod markup;
+pub mod parser;
+pub use parser::{parse_unit, ParseUnitError};
+pub mod quantity;
+pub use quantity::Quantity;
+pub mod registry;
+pub use registry::UnitRegistry;
+pub mod system;
+pub use system::{SystemUnits, UnitSystem};
+#[cfg(feature = "serde")]
+pub mod serialization;
+#[cfg(test)]
+mod testing;
@@ -0,0 +1,199 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::format::FormatOptions;
+use crate::units::UnitTerm;
+use crate::EngUnit;
+
+impl EngUnit {
+    /// HTML fragment such as `12.30 kg·m<sup>2</sup>/s<sup>2</sup>`.
+    pub fn to_html(&self) -> String {
+        self.to_html_with(&FormatOptions::new())
+    }
+
+    pub fn to_html_with(&self, options: &FormatOptions) -> String {
+        let (unit, value) = self.formatted_parts(options);
+        let (numerator, denominator) = unit.unit_terms(options.unit_order);
+        let mut s = escape(&value);
+        if numerator.is_empty() && denominator.is_empty() {
+            return s;
+        }
+        s.push(' ');
+        s.push_str(&html_terms(&numerator));
+        if !denominator.is_empty() {
+            s.push('/');
+            s.push_str(&html_terms(&denominator));
+        }
+        s
+    }
+
+    /// MathML `<math>` element with `<mn>` for the value and `<mi>`/`<msup>`
+    /// for the units. A unit with only a denominator is written `1/s`.
+    pub fn to_mathml(&self) -> String {
+        self.to_mathml_with(&FormatOptions::new())
+    }
+
+    pub fn to_mathml_with(&self, options: &FormatOptions) -> String {
+        let (unit, value) = self.formatted_parts(options);
+        let (numerator, denominator) = unit.unit_terms(options.unit_order);
+        let mut s = format!("<math><mrow><mn>{}</mn>", escape(&value));
+        if !numerator.is_empty() || !denominator.is_empty() {
+            s.push_str("<mspace width=\"0.25em\"/>");
+            if numerator.is_empty() {
+                s.push_str("<mn>1</mn>");
+            } else {
+                s.push_str(&mathml_terms(&numerator));
+            }
+            if !denominator.is_empty() {
+                s.push_str("<mo>/</mo>");
+                s.push_str(&mathml_terms(&denominator));
+            }
+        }
+        s.push_str("</mrow></math>");
+        s
+    }
+}
+
+fn html_terms(terms: &[UnitTerm]) -> String {
+    terms
+        .iter()
+        .map(|term| {
+            if term.exponent > 1 {
+                format!("{}<sup>{}</sup>", escape(term.symbol), term.exponent)
+            } else {
+                escape(term.symbol)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("·")
+}
+
+fn mathml_terms(terms: &[UnitTerm]) -> String {
+    let mut s = String::from("<mrow>");
+    for (index, term) in terms.iter().enumerate() {
+        if index > 0 {
+            s.push_str("<mo>·</mo>");
+        }
+        let symbol = format!("<mi mathvariant=\"normal\">{}</mi>", escape(term.symbol));
+        if term.exponent > 1 {
+            s.push_str(&format!(
+                "<msup>{}<mn>{}</mn></msup>",
+                symbol, term.exponent
+            ));
+        } else {
+            s.push_str(&symbol);
+        }
+    }
+    s.push_str("</mrow>");
+    s
+}
+
+/// Escapes the characters that are special in HTML and XML text.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::{FormatOptions, Notation};
+    use crate::markup::escape;
+    use crate::prefix::ENGINEERING_PREFIXES;
+    use crate::units::length_unit::LengthUnit;
+    use crate::units::mass_unit::MassUnit;
+    use crate::units::temperature_unit::TemperatureDeltaUnit;
+    use crate::units::time_unit::TimeUnit;
+    use crate::{kJ, length, mass, temperature, time, EngUnit, W};
+
+    fn energy() -> EngUnit {
+        let m = mass!(12.3, MassUnit::Kilogram);
+        let l = length!(1.0, LengthUnit::Meter);
+        let t = time!(1.0, TimeUnit::Second);
+        m * l.clone() * l / (t.clone() * t)
+    }
+
+    #[test]
+    fn html() {
+        assert_eq!("12.30 kg·m<sup>2</sup>/s<sup>2</sup>", energy().to_html());
+        let cp = kJ!(4.2) / temperature!(1.0, TemperatureDeltaUnit::C);
+        assert_eq!("4.20 kJ/°C", cp.to_html());
+        assert_eq!(
+            "2.00",
+            EngUnit {
+                value: 2.0,
+                ..EngUnit::new()
+            }
+            .to_html()
+        );
+    }
+
+    #[test]
+    fn html_with_options() {
+        let options = FormatOptions::new()
+            .notation(Notation::Scientific)
+            .precision(1);
+        assert_eq!(
+            "1.2e1 kg·m<sup>2</sup>/s<sup>2</sup>",
+            energy().to_html_with(&options)
+        );
+        let options = FormatOptions::new().auto_prefix(ENGINEERING_PREFIXES);
+        assert_eq!("12.50 MW", W!(12_500_000.0).to_html_with(&options));
+    }
+
+    #[test]
+    fn mathml() {
+        assert_eq!(
+            "<math><mrow><mn>12.30</mn><mspace width=\"0.25em\"/>\
+             <mrow><mi mathvariant=\"normal\">kg</mi><mo>·</mo>\
+             <msup><mi mathvariant=\"normal\">m</mi><mn>2</mn></msup></mrow>\
+             <mo>/</mo>\
+             <mrow><msup><mi mathvariant=\"normal\">s</mi><mn>2</mn></msup></mrow>\
+             </mrow></math>",
+            energy().to_mathml()
+        );
+        assert_eq!(
+            "<math><mrow><mn>2.0</mn></mrow></math>",
+            EngUnit {
+                value: 2.0,
+                ..EngUnit::new()
+            }
+            .to_mathml_with(&FormatOptions::new().precision(1))
+        );
+        let frequency = EngUnit::new() / time!(1.0, TimeUnit::Second);
+        assert_eq!(
+            "<math><mrow><mn>1.00</mn><mspace width=\"0.25em\"/>\
+             <mn>1</mn><mo>/</mo>\
+             <mrow><mi mathvariant=\"normal\">s</mi></mrow>\
+             </mrow></math>",
+            frequency.to_mathml()
+        );
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!("a&amp;b&lt;c&gt;&quot;&#39;", escape("a&b<c>\"'"));
+    }
+}
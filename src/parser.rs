@@ -0,0 +1,525 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::complex_units::{find_complex_unit, ComplexUnit, COMPLEX_UNITS};
+use crate::error::{check_finite, EngUnitError};
+use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
+use crate::units::electric_current_unit::ElectricCurrentUnit;
+use crate::units::length_unit::LengthUnit;
+use crate::units::luminous_intensity_unit::LuminousIntensityUnit;
+use crate::units::mass_unit::MassUnit;
+use crate::units::temperature_unit::TemperatureDeltaUnit;
+use crate::units::time_unit::TimeUnit;
+use crate::units::BaseDimension;
+use crate::EngUnit;
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Error returned when a unit expression cannot be parsed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseUnitError {
+    /// The input was empty or only whitespace.
+    Empty,
+    /// The leading numeric value could not be read.
+    InvalidValue(String),
+    /// An exponent after `^` is not an integer.
+    InvalidExponent(String),
+    /// The same dimension was given in two different units, e.g. `m·ft`.
+    ConflictingUnits(String),
+}
+
+impl Display for ParseUnitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseUnitError::Empty => write!(f, "empty unit expression"),
+            ParseUnitError::InvalidValue(s) => write!(f, "invalid value '{s}'"),
+            ParseUnitError::InvalidExponent(s) => write!(f, "invalid exponent '{s}'"),
+            ParseUnitError::ConflictingUnits(s) => {
+                write!(
+                    f,
+                    "unit '{s}' conflicts with another unit of the same dimension"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseUnitError {}
+
+impl FromStr for EngUnit {
+    type Err = EngUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_unit(s)
+    }
+}
+
+/// Parses a unit expression such as `"12.3 kg·m^2/s^2"` or `"4.5 kJ/K"`.
+///
+/// The grammar is the one emitted by [`EngUnit::unit_to_string`]: a numeric
+/// value followed by factors joined with `·` or `*`, an optional `/` before
+/// the denominator factors, and integer exponents written as `^n`. The ASCII
+/// spellings of [`EngUnit::unit_to_ascii_string`] are accepted as well.
+///
+/// Unknown symbols are reported as [`EngUnitError::UnknownUnit`], values
+/// that overflow to infinity as [`EngUnitError::NonFiniteValue`] and any
+/// other syntax problem as [`EngUnitError::Parse`].
+pub fn parse_unit(s: &str) -> Result<EngUnit, EngUnitError> {
+    parse_unit_with(s, &[])
+}
+
+/// Like [`parse_unit`] but also accepts the named units in `extra`, which
+/// are looked up before [`crate::complex_units::COMPLEX_UNITS`].
+pub(crate) fn parse_unit_with(s: &str, extra: &[ComplexUnit]) -> Result<EngUnit, EngUnitError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseUnitError::Empty.into());
+    }
+
+    let (value, units) = split_value(s)?;
+    let mut unit = EngUnit::new();
+    unit.value = check_finite(value)?;
+
+    for (index, part) in units.split('/').enumerate() {
+        let sign = if index == 0 { 1 } else { -1 };
+        for factor in split_factors(part) {
+            let factor = factor.as_str();
+            if factor.is_empty() || factor == "1" {
+                continue;
+            }
+            let (symbol, exponent) = split_exponent(factor)?;
+            let exponent = exponent
+                .checked_mul(sign)
+                .ok_or_else(|| invalid_exponent(exponent))?;
+            let result = push_factor(&mut unit, symbol, exponent, extra);
+            match (result, from_ascii_symbol(symbol)) {
+                (Err(EngUnitError::UnknownUnit(_)), Some(unicode)) => {
+                    match push_factor(&mut unit, &unicode, exponent, extra) {
+                        Err(EngUnitError::UnknownUnit(_)) => {
+                            return Err(EngUnitError::UnknownUnit(symbol.to_string()))
+                        }
+                        result => result?,
+                    }
+                }
+                (result, _) => result?,
+            }
+        }
+    }
+    unit.clear_unused_units();
+    Ok(unit)
+}
+
+/// Factors of `part` separated by `·` or `*`. Catalog symbols that are a
+/// product themselves, such as `ft·lbf`, are kept whole with either
+/// separator.
+fn split_factors(part: &str) -> Vec<String> {
+    let mut factors = Vec::new();
+    let mut pieces = part.split(['·', '*']).map(str::trim).peekable();
+    while let Some(piece) = pieces.next() {
+        let product = pieces.peek().and_then(|next| {
+            let symbol = next.split('^').next().unwrap_or(next).trim();
+            let product = format!("{piece}·{symbol}");
+            COMPLEX_UNITS
+                .iter()
+                .any(|complex| complex.unit_string == product)
+                .then(|| format!("{piece}·{next}"))
+        });
+        match product {
+            Some(product) => {
+                pieces.next();
+                factors.push(product);
+            }
+            None => factors.push(piece.to_string()),
+        }
+    }
+    factors
+}
+
+fn split_value(s: &str) -> Result<(f64, &str), ParseUnitError> {
+    let end = s
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '+' | '-' | 'e' | 'E')))
+        .unwrap_or(s.len());
+
+    // Walk back until the prefix is a number so that a trailing "e" belonging
+    // to a unit symbol is not mistaken for an incomplete exponent.
+    let mut end = end;
+    while end > 0 {
+        if let Ok(value) = s[..end].parse::<f64>() {
+            return Ok((value, s[end..].trim()));
+        }
+        end -= 1;
+    }
+    let token = s.split_whitespace().next().unwrap_or(s);
+    Err(ParseUnitError::InvalidValue(token.to_string()))
+}
+
+fn split_exponent(factor: &str) -> Result<(&str, i32), ParseUnitError> {
+    match factor.split_once('^') {
+        None => Ok((factor, 1)),
+        Some((symbol, exponent)) => {
+            let exponent = exponent
+                .trim()
+                .trim_start_matches('(')
+                .trim_end_matches(')');
+            match exponent.parse::<i32>() {
+                Ok(exponent) => Ok((symbol.trim(), exponent)),
+                Err(_) => Err(ParseUnitError::InvalidExponent(exponent.to_string())),
+            }
+        }
+    }
+}
+
+/// Unicode spelling of an ASCII unit symbol written by
+/// [`EngUnit::unit_to_ascii_string`], e.g. `degC`, `um` or `kohm`.
+fn from_ascii_symbol(symbol: &str) -> Option<String> {
+    let unicode = match symbol.strip_prefix("deg") {
+        Some(rest) => format!("°{rest}"),
+        None => symbol.to_string(),
+    };
+    let unicode = match unicode.strip_suffix("ohm") {
+        Some(rest) => format!("{rest}Ω"),
+        None => unicode,
+    };
+    let unicode = match unicode.strip_prefix('u') {
+        Some(rest) if !rest.is_empty() => format!("µ{rest}"),
+        _ => unicode,
+    };
+    if unicode == symbol {
+        None
+    } else {
+        Some(unicode)
+    }
+}
+
+/// Largest exponent accepted for a named unit such as `kJ`. Every power is
+/// stored as a separate entry, so the limit bounds the allocation.
+const MAX_NAMED_UNIT_EXPONENT: u32 = 32;
+
+fn invalid_exponent(exponent: i32) -> EngUnitError {
+    ParseUnitError::InvalidExponent(exponent.to_string()).into()
+}
+
+/// Adds `exponent` to a dimension count, failing instead of overflowing.
+fn add_exponent(count: &mut i32, exponent: i32) -> Result<(), EngUnitError> {
+    *count = count
+        .checked_add(exponent)
+        .ok_or_else(|| invalid_exponent(exponent))?;
+    Ok(())
+}
+
+fn push_factor(
+    unit: &mut EngUnit,
+    symbol: &str,
+    exponent: i32,
+    extra: &[ComplexUnit],
+) -> Result<(), EngUnitError> {
+    let conflict = || EngUnitError::from(ParseUnitError::ConflictingUnits(symbol.to_string()));
+
+    if let Some(u) = AmountOfSubstanceUnit::from_string(symbol) {
+        if unit.amount_of_substance_count != 0 && unit.amount_of_substance_unit != u {
+            return Err(conflict());
+        }
+        unit.amount_of_substance_unit = u;
+        add_exponent(&mut unit.amount_of_substance_count, exponent)?;
+        unit.record_dimension(BaseDimension::AmountOfSubstance);
+    } else if let Some(u) = ElectricCurrentUnit::from_string(symbol) {
+        if unit.electric_current_count != 0 && unit.electric_current_unit != u {
+            return Err(conflict());
+        }
+        unit.electric_current_unit = u;
+        add_exponent(&mut unit.electric_current_count, exponent)?;
+        unit.record_dimension(BaseDimension::ElectricCurrent);
+    } else if let Some(u) = LengthUnit::from_string(symbol) {
+        if unit.length_count != 0 && unit.length_unit != u {
+            return Err(conflict());
+        }
+        unit.length_unit = u;
+        add_exponent(&mut unit.length_count, exponent)?;
+        unit.record_dimension(BaseDimension::Length);
+    } else if let Some(u) = LuminousIntensityUnit::from_string(symbol) {
+        if unit.luminous_intensity_count != 0 && unit.luminous_intensity_unit != u {
+            return Err(conflict());
+        }
+        unit.luminous_intensity_unit = u;
+        add_exponent(&mut unit.luminous_intensity_count, exponent)?;
+        unit.record_dimension(BaseDimension::LuminousIntensity);
+    } else if let Some(u) = MassUnit::from_string(symbol) {
+        if unit.mass_count != 0 && unit.mass_unit != u {
+            return Err(conflict());
+        }
+        unit.mass_unit = u;
+        add_exponent(&mut unit.mass_count, exponent)?;
+        unit.record_dimension(BaseDimension::Mass);
+    } else if let Some(u) = TemperatureDeltaUnit::from_string(symbol) {
+        if unit.temperature_count != 0 && unit.temperature_unit != u {
+            return Err(conflict());
+        }
+        unit.temperature_unit = u;
+        add_exponent(&mut unit.temperature_count, exponent)?;
+        unit.record_dimension(BaseDimension::Temperature);
+    } else if let Some(u) = TimeUnit::from_string(symbol) {
+        if unit.time_count != 0 && unit.time_unit != u {
+            return Err(conflict());
+        }
+        unit.time_unit = u;
+        add_exponent(&mut unit.time_count, exponent)?;
+        unit.record_dimension(BaseDimension::Time);
+    } else if let Some(complex) = extra
+        .iter()
+        .find(|complex| complex.unit_string == symbol)
+        .copied()
+        .or_else(|| find_complex_unit(symbol))
+    {
+        if exponent.unsigned_abs() > MAX_NAMED_UNIT_EXPONENT {
+            return Err(invalid_exponent(exponent));
+        }
+        for _ in 0..exponent.abs() {
+            if exponent > 0 {
+                unit.unit_numerator.push(complex);
+            } else {
+                unit.unit_denominator.push(complex);
+            }
+        }
+    } else {
+        return Err(EngUnitError::UnknownUnit(symbol.to_string()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex_units::energy_unit::KILOJOULE;
+    use crate::{kJ, mass, temperature, time};
+
+    #[test]
+    fn value_only() {
+        let u = parse_unit("12.5").unwrap();
+        assert_eq!(12.5, u.value);
+        assert_eq!("12.50", u.to_string());
+    }
+
+    #[test]
+    fn single_unit() {
+        let u: EngUnit = "0.25 lb".parse().unwrap();
+        assert_eq!(0.25, u.value);
+        assert_eq!(1, u.mass_count);
+        assert_eq!(MassUnit::Pound, u.mass_unit);
+    }
+
+    #[test]
+    fn no_space() {
+        let u = parse_unit("3kg").unwrap();
+        assert_eq!(3.0, u.value);
+        assert_eq!(MassUnit::Kilogram, u.mass_unit);
+    }
+
+    #[test]
+    fn scientific_value() {
+        let u = parse_unit("1.5e-3 m").unwrap();
+        assert_eq!(1.5e-3, u.value);
+        assert_eq!(1, u.length_count);
+    }
+
+    #[test]
+    fn exponents_and_denominator() {
+        let u = parse_unit("12.3 kg·m^2/s^2").unwrap();
+        assert_eq!(12.3, u.value);
+        assert_eq!(1, u.mass_count);
+        assert_eq!(2, u.length_count);
+        assert_eq!(-2, u.time_count);
+        assert_eq!(LengthUnit::Meter, u.length_unit);
+        assert_eq!(TimeUnit::Second, u.time_unit);
+        assert_eq!("12.30 kg·m^2/s^2", u.to_string());
+    }
+
+    #[test]
+    fn star_separator() {
+        let u = parse_unit("2 kg*m/s^2").unwrap();
+        assert_eq!("2.00 kg·m/s^2", u.to_string());
+    }
+
+    #[test]
+    fn negative_exponent() {
+        let u = parse_unit("2 m^-2").unwrap();
+        assert_eq!(-2, u.length_count);
+        assert_eq!("2.00 /m^2", u.to_string());
+    }
+
+    #[test]
+    fn reciprocal_round_trip() {
+        let u = parse_unit("0.5 /s").unwrap();
+        assert_eq!(-1, u.time_count);
+        assert_eq!("0.50 /s", u.to_string());
+    }
+
+    #[test]
+    fn temperature() {
+        let u = parse_unit("4 kg·°C/s").unwrap();
+        assert_eq!(TemperatureDeltaUnit::C, u.temperature_unit);
+        assert_eq!("4.00 kg·°C/s", u.to_string());
+    }
+
+    #[test]
+    fn complex_numerator() {
+        let u = parse_unit("12.3 kJ").unwrap();
+        assert_eq!(vec![KILOJOULE], u.unit_numerator);
+        assert_eq!(0, u.mass_count);
+        let expected = kJ!(12.3);
+        assert_eq!(expected.to_si_units().value, u.to_si_units().value);
+    }
+
+    #[test]
+    fn complex_with_base_units() {
+        let u = parse_unit("1.23 kJ/K").unwrap();
+        assert_eq!("1.23 kJ/K", u.to_string());
+        let u = parse_unit("6 kJ^2").unwrap();
+        assert_eq!(2, u.unit_numerator.len());
+        assert_eq!("6.00 kJ^2", u.to_string());
+    }
+
+    #[test]
+    fn derived_units() {
+        for symbol in ["N", "kPa", "MHz", "µC", "kV", "MΩ", "pF", "mH", "µT", "Wb"] {
+            let u = parse_unit(&format!("1 {symbol}")).unwrap();
+            assert_eq!(format!("1.00 {symbol}"), u.to_string());
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let u1 = temperature!(4.0, TemperatureDeltaUnit::C);
+        let u2 = mass!(5.0, MassUnit::Kilogram);
+        let u3 = time!(10.0, TimeUnit::Second);
+        let unit = (u1 * u2 / u3).reciprocal();
+        let parsed = parse_unit(&unit.to_string()).unwrap();
+        assert_eq!(unit.to_string(), parsed.to_string());
+        assert_eq!(unit.mass_count, parsed.mass_count);
+        assert_eq!(unit.temperature_count, parsed.temperature_count);
+        assert_eq!(unit.time_count, parsed.time_count);
+    }
+
+    #[test]
+    fn repeated_factor() {
+        let u = parse_unit("1 m·m/s").unwrap();
+        assert_eq!(2, u.length_count);
+        let u = parse_unit("1 m/m").unwrap();
+        assert_eq!(0, u.length_count);
+        assert_eq!(LengthUnit::None, u.length_unit);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            EngUnitError::Parse(ParseUnitError::Empty),
+            parse_unit("   ").unwrap_err()
+        );
+        assert_eq!(
+            EngUnitError::Parse(ParseUnitError::InvalidValue("kg".to_string())),
+            parse_unit("kg").unwrap_err()
+        );
+        assert_eq!(
+            EngUnitError::UnknownUnit("furlong".to_string()),
+            parse_unit("1 furlong").unwrap_err()
+        );
+        assert_eq!(
+            EngUnitError::UnknownUnit("uJ".to_string()),
+            parse_unit("2 uJ").unwrap_err()
+        );
+        assert_eq!(
+            EngUnitError::UnknownUnit("degX".to_string()),
+            parse_unit("2 degX").unwrap_err()
+        );
+        assert_eq!(
+            EngUnitError::Parse(ParseUnitError::InvalidExponent("x".to_string())),
+            parse_unit("1 m^x").unwrap_err()
+        );
+        assert_eq!(
+            EngUnitError::Parse(ParseUnitError::ConflictingUnits("ft".to_string())),
+            parse_unit("1 m·ft").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn non_finite_value() {
+        assert_eq!(
+            EngUnitError::NonFiniteValue(f64::INFINITY),
+            parse_unit("1e400 m").unwrap_err()
+        );
+        assert_eq!(
+            EngUnitError::NonFiniteValue(f64::NEG_INFINITY),
+            parse_unit("-1e400").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn exponent_limits() {
+        let invalid = |s: &str| EngUnitError::Parse(ParseUnitError::InvalidExponent(s.to_string()));
+        assert_eq!(
+            invalid("-2147483648"),
+            parse_unit("1 kJ^-2147483648").unwrap_err()
+        );
+        assert_eq!(
+            invalid("-2147483648"),
+            parse_unit("1 /m^-2147483648").unwrap_err()
+        );
+        assert_eq!(invalid("1"), parse_unit("1 m^2147483647·m").unwrap_err());
+        assert_eq!(
+            invalid("2000000000"),
+            parse_unit("1 kJ^2000000000").unwrap_err()
+        );
+        assert_eq!(invalid("-33"), parse_unit("1 /kJ^33").unwrap_err());
+        let u = parse_unit("1 kJ^32").unwrap();
+        assert_eq!(32, u.unit_numerator.len());
+        let u = parse_unit("1 m^2147483647").unwrap();
+        assert_eq!(i32::MAX, u.length_count);
+    }
+
+    #[test]
+    fn error_display() {
+        let e = parse_unit("1 furlong").unwrap_err();
+        assert_eq!("unknown unit 'furlong'", e.to_string());
+        let e = parse_unit("1 m^x").unwrap_err();
+        assert_eq!("invalid exponent 'x'", e.to_string());
+    }
+
+    #[test]
+    fn ascii_spellings() {
+        let u = parse_unit("4 kg*degC/s").unwrap();
+        assert_eq!(TemperatureDeltaUnit::C, u.temperature_unit);
+        assert_eq!("4.00 kg·°C/s", u.to_string());
+        let u = parse_unit("3 um^2").unwrap();
+        assert_eq!(LengthUnit::Micrometer, u.length_unit);
+        assert_eq!(2, u.length_count);
+        assert_eq!("2.00 kΩ", parse_unit("2 kohm").unwrap().to_string());
+        assert_eq!("2.00 Ω", parse_unit("2 ohm").unwrap().to_string());
+        assert_eq!("2.00 µF", parse_unit("2 uF").unwrap().to_string());
+        assert_eq!("2.00 u", parse_unit("2 u").unwrap().to_string());
+        assert!(parse_unit("2 degX").is_err());
+    }
+
+    #[test]
+    fn ascii_round_trip() {
+        let u1 = temperature!(4.0, TemperatureDeltaUnit::F);
+        let u2 = mass!(5.0, MassUnit::Kilogram);
+        let u3 = time!(10.0, TimeUnit::Microsecond);
+        let unit = (u1 * u2 / u3).reciprocal();
+        let parsed = parse_unit(&unit.to_ascii_string()).unwrap();
+        assert_eq!(unit.to_string(), parsed.to_string());
+        assert_eq!(unit.to_ascii_string(), parsed.to_ascii_string());
+    }
+}
@@ -0,0 +1,367 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::complex_units::{ComplexUnit, COMPLEX_UNITS};
+use crate::units::length_unit::LengthUnit;
+use crate::units::mass_unit::MassUnit;
+use crate::units::time_unit::TimeUnit;
+use crate::EngUnit;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SiPrefix {
+    Pico,
+    Nano,
+    Micro,
+    Milli,
+    Centi,
+    Deci,
+    None,
+    Deca,
+    Hecto,
+    Kilo,
+    Mega,
+    Giga,
+    Tera,
+}
+
+/// Every prefix supported by [`EngUnit::with_auto_prefix`].
+pub const ALL_PREFIXES: &[SiPrefix] = &[
+    SiPrefix::Pico,
+    SiPrefix::Nano,
+    SiPrefix::Micro,
+    SiPrefix::Milli,
+    SiPrefix::Centi,
+    SiPrefix::Deci,
+    SiPrefix::None,
+    SiPrefix::Deca,
+    SiPrefix::Hecto,
+    SiPrefix::Kilo,
+    SiPrefix::Mega,
+    SiPrefix::Giga,
+    SiPrefix::Tera,
+];
+
+/// Prefixes that are powers of 1000, as used in engineering output.
+pub const ENGINEERING_PREFIXES: &[SiPrefix] = &[
+    SiPrefix::Pico,
+    SiPrefix::Nano,
+    SiPrefix::Micro,
+    SiPrefix::Milli,
+    SiPrefix::None,
+    SiPrefix::Kilo,
+    SiPrefix::Mega,
+    SiPrefix::Giga,
+    SiPrefix::Tera,
+];
+
+impl SiPrefix {
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            SiPrefix::Pico => "p",
+            SiPrefix::Nano => "n",
+            SiPrefix::Micro => "µ",
+            SiPrefix::Milli => "m",
+            SiPrefix::Centi => "c",
+            SiPrefix::Deci => "d",
+            SiPrefix::None => "",
+            SiPrefix::Deca => "da",
+            SiPrefix::Hecto => "h",
+            SiPrefix::Kilo => "k",
+            SiPrefix::Mega => "M",
+            SiPrefix::Giga => "G",
+            SiPrefix::Tera => "T",
+        }
+    }
+
+    pub fn factor(&self) -> f64 {
+        match self {
+            SiPrefix::Pico => 1.0e-12,
+            SiPrefix::Nano => 1.0e-9,
+            SiPrefix::Micro => 1.0e-6,
+            SiPrefix::Milli => 1.0e-3,
+            SiPrefix::Centi => 1.0e-2,
+            SiPrefix::Deci => 1.0e-1,
+            SiPrefix::None => 1.0,
+            SiPrefix::Deca => 1.0e1,
+            SiPrefix::Hecto => 1.0e2,
+            SiPrefix::Kilo => 1.0e3,
+            SiPrefix::Mega => 1.0e6,
+            SiPrefix::Giga => 1.0e9,
+            SiPrefix::Tera => 1.0e12,
+        }
+    }
+}
+
+const LENGTH_PREFIXES: &[(SiPrefix, LengthUnit)] = &[
+    (SiPrefix::Nano, LengthUnit::Nanometer),
+    (SiPrefix::Micro, LengthUnit::Micrometer),
+    (SiPrefix::Milli, LengthUnit::Millimeter),
+    (SiPrefix::Centi, LengthUnit::Centimeter),
+    (SiPrefix::None, LengthUnit::Meter),
+    (SiPrefix::Kilo, LengthUnit::Kilometer),
+];
+
+/// Mass prefixes apply to the gram.
+const MASS_PREFIXES: &[(SiPrefix, MassUnit)] = &[
+    (SiPrefix::Micro, MassUnit::Microgram),
+    (SiPrefix::Milli, MassUnit::Milligram),
+    (SiPrefix::None, MassUnit::Gram),
+    (SiPrefix::Kilo, MassUnit::Kilogram),
+];
+
+const TIME_PREFIXES: &[(SiPrefix, TimeUnit)] = &[
+    (SiPrefix::Nano, TimeUnit::Nanosecond),
+    (SiPrefix::Micro, TimeUnit::Microsecond),
+    (SiPrefix::Milli, TimeUnit::Millisecond),
+    (SiPrefix::None, TimeUnit::Second),
+];
+
+impl EngUnit {
+    /// Re-expresses the unit with the prefix from `allowed` that puts the
+    /// value in `[1, 1000)`, e.g. `0.0000047 F` becomes `4.7 µF`.
+    ///
+    /// Applies to a single named unit in the numerator, which may be
+    /// followed by other units (`2100 J/kg·K` becomes `2.1 kJ/kg·K`), and
+    /// to a lone metric length, mass or time with a positive exponent. A
+    /// power scales with the prefix to that power, so `0.00003 m^2` becomes
+    /// `30 mm^2`. Anything else, and values that are zero or not finite, are
+    /// returned unchanged. If no allowed prefix reaches the range, as often
+    /// for a power, the largest prefix that keeps the value at or above 1 is
+    /// used, or else the smallest allowed prefix.
+    pub fn with_auto_prefix(&self, allowed: &[SiPrefix]) -> EngUnit {
+        if self.value == 0.0 || !self.value.is_finite() {
+            return self.clone();
+        }
+        let candidates = self.prefix_candidates();
+        let candidates: Vec<(SiPrefix, EngUnit)> = candidates
+            .into_iter()
+            .filter(|(prefix, _)| allowed.contains(prefix))
+            .collect();
+        let best = candidates
+            .iter()
+            .filter(|(_, unit)| unit.value.abs() >= 1.0)
+            .max_by(|a, b| a.0.factor().total_cmp(&b.0.factor()))
+            .or_else(|| {
+                candidates
+                    .iter()
+                    .min_by(|a, b| a.0.factor().total_cmp(&b.0.factor()))
+            });
+        match best {
+            Some((_, unit)) => unit.clone(),
+            None => self.clone(),
+        }
+    }
+
+    /// Display string of [`EngUnit::with_auto_prefix`] using
+    /// [`ENGINEERING_PREFIXES`].
+    pub fn to_prefixed_string(&self) -> String {
+        self.with_auto_prefix(ENGINEERING_PREFIXES).to_string()
+    }
+
+    fn prefix_candidates(&self) -> Vec<(SiPrefix, EngUnit)> {
+        if !self.unit_denominator.is_empty() && self.unit_numerator.is_empty() {
+            return Vec::new();
+        }
+        if self.unit_numerator.len() == 1 {
+            return self.complex_prefix_candidates(self.unit_numerator[0]);
+        }
+        if !self.unit_numerator.is_empty() || !self.unit_denominator.is_empty() {
+            return Vec::new();
+        }
+
+        let counts = [
+            self.amount_of_substance_count,
+            self.electric_current_count,
+            self.length_count,
+            self.luminous_intensity_count,
+            self.mass_count,
+            self.temperature_count,
+            self.time_count,
+        ];
+        if counts.iter().filter(|count| **count != 0).count() != 1 {
+            return Vec::new();
+        }
+        if self.length_count > 0 && LENGTH_PREFIXES.iter().any(|x| x.1 == self.length_unit) {
+            LENGTH_PREFIXES
+                .iter()
+                .map(|(prefix, unit)| (*prefix, self.convert(*unit)))
+                .collect()
+        } else if self.mass_count > 0 && MASS_PREFIXES.iter().any(|x| x.1 == self.mass_unit) {
+            MASS_PREFIXES
+                .iter()
+                .map(|(prefix, unit)| (*prefix, self.convert(*unit)))
+                .collect()
+        } else if self.time_count > 0 && TIME_PREFIXES.iter().any(|x| x.1 == self.time_unit) {
+            TIME_PREFIXES
+                .iter()
+                .map(|(prefix, unit)| (*prefix, self.convert(*unit)))
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn complex_prefix_candidates(&self, current: ComplexUnit) -> Vec<(SiPrefix, EngUnit)> {
+        let coherent = COMPLEX_UNITS.iter().find(|complex| {
+            complex.prefix_multiplier == 1.0
+                && same_base(complex, &current)
+                && prefix_of(complex, &current).is_some()
+        });
+        let Some(coherent) = coherent else {
+            return Vec::new();
+        };
+        COMPLEX_UNITS
+            .iter()
+            .filter(|complex| same_base(complex, coherent))
+            .filter_map(|complex| {
+                let prefix = prefix_of(coherent, complex)?;
+                let mut unit = self.clone();
+                unit.value *= complex.prefix_multiplier / current.prefix_multiplier;
+                unit.unit_numerator[0] = *complex;
+                Some((prefix, unit))
+            })
+            .collect()
+    }
+}
+
+/// The prefix that turns `coherent` into `complex`, if `complex` is a
+/// prefixed form of `coherent`.
+fn prefix_of(coherent: &ComplexUnit, complex: &ComplexUnit) -> Option<SiPrefix> {
+    let symbol = complex.unit_string.strip_suffix(coherent.unit_string)?;
+    let prefix = ALL_PREFIXES
+        .iter()
+        .find(|prefix| prefix.to_string() == symbol)?;
+    let factor = coherent.prefix_multiplier / complex.prefix_multiplier;
+    if f64::abs(factor / prefix.factor() - 1.0) < 1e-9 {
+        Some(*prefix)
+    } else {
+        None
+    }
+}
+
+fn same_base(a: &ComplexUnit, b: &ComplexUnit) -> bool {
+    a.amount_of_substance_count == b.amount_of_substance_count
+        && a.electric_current_count == b.electric_current_count
+        && a.length_count == b.length_count
+        && a.luminous_intensity_count == b.luminous_intensity_count
+        && a.mass_count == b.mass_count
+        && a.temperature_count == b.temperature_count
+        && a.time_count == b.time_count
+        && a.amount_of_substance_unit == b.amount_of_substance_unit
+        && a.electric_current_unit == b.electric_current_unit
+        && a.length_unit == b.length_unit
+        && a.luminous_intensity_unit == b.luminous_intensity_unit
+        && a.mass_unit == b.mass_unit
+        && a.temperature_unit == b.temperature_unit
+        && a.time_unit == b.time_unit
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prefix::*;
+    use crate::{hp, kW, length, mass, time, EngUnit, F, J, W};
+
+    #[test]
+    fn capacitance() {
+        assert_eq!("4.70 µF", F!(0.0000047).to_prefixed_string());
+        assert_eq!("470.00 pF", F!(4.7e-10).to_prefixed_string());
+    }
+
+    #[test]
+    fn power() {
+        assert_eq!("12.50 MW", W!(12_500_000.0).to_prefixed_string());
+        assert_eq!("999.00 W", W!(999.0).to_prefixed_string());
+        assert_eq!("1.00 GW", W!(1.0e9).to_prefixed_string());
+        assert_eq!("50.00 TW", W!(5.0e13).to_prefixed_string());
+        assert_eq!("2.00 TJ", J!(2.0e12).to_prefixed_string());
+    }
+
+    #[test]
+    fn prefixed_complex_unit() {
+        assert_eq!("2.50 MW", kW!(2500.0).to_prefixed_string());
+        let unit = J!(2100.0) / mass!(1.0, MassUnit::Kilogram);
+        assert_eq!("2.10 kJ/kg", unit.to_prefixed_string());
+    }
+
+    #[test]
+    fn base_units() {
+        assert_eq!(
+            "4.70 µm",
+            length!(0.0000047, LengthUnit::Meter).to_prefixed_string()
+        );
+        assert_eq!(
+            "12.50 km",
+            length!(12500.0, LengthUnit::Meter).to_prefixed_string()
+        );
+        assert_eq!(
+            "250.00 g",
+            mass!(0.25, MassUnit::Kilogram).to_prefixed_string()
+        );
+        assert_eq!(
+            "3.00 ns",
+            time!(3e-9, TimeUnit::Second).to_prefixed_string()
+        );
+    }
+
+    #[test]
+    fn powered_base_units() {
+        let area: EngUnit = "0.00003 m^2".parse().unwrap();
+        assert_eq!("30.00 mm^2", area.to_prefixed_string());
+        let area: EngUnit = "0.003 m^2".parse().unwrap();
+        assert_eq!("3000.00 mm^2", area.to_prefixed_string());
+        let area = length!(0.001, LengthUnit::Meter) * length!(1.0, LengthUnit::Meter);
+        assert_eq!("1000.00 mm^2", area.to_prefixed_string());
+        let volume: EngUnit = "2e9 m^3".parse().unwrap();
+        assert_eq!("2.00 km^3", volume.to_prefixed_string());
+        let area: EngUnit = "3 cm^2".parse().unwrap();
+        assert_eq!("300.00 mm^2", area.to_prefixed_string());
+    }
+
+    #[test]
+    fn restricted_prefixes() {
+        let unit = length!(0.05, LengthUnit::Meter);
+        assert_eq!("50.00 mm", unit.to_prefixed_string());
+        assert_eq!("5.00 cm", unit.with_auto_prefix(ALL_PREFIXES).to_string());
+        let unit = W!(5.0e13);
+        assert_eq!(
+            "50000.00 GW",
+            unit.with_auto_prefix(&[SiPrefix::Mega, SiPrefix::Giga])
+                .to_string()
+        );
+        let allowed = [SiPrefix::None, SiPrefix::Kilo];
+        assert_eq!(
+            "50000000000.00 kW",
+            unit.with_auto_prefix(&allowed).to_string()
+        );
+        let unit = W!(0.001);
+        assert_eq!(
+            "0.00 kW",
+            unit.with_auto_prefix(&[SiPrefix::Kilo]).to_string()
+        );
+    }
+
+    #[test]
+    fn unchanged() {
+        assert_eq!("2.00 hp", hp!(2.0).to_prefixed_string());
+        assert_eq!("0.00 W", W!(0.0).to_prefixed_string());
+        assert_eq!(
+            "2.00 ft",
+            length!(2.0, LengthUnit::Foot).to_prefixed_string()
+        );
+        let wavenumber: EngUnit = "0.5 /m".parse().unwrap();
+        assert_eq!("0.50 /m", wavenumber.to_prefixed_string());
+    }
+}
@@ -0,0 +1,556 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Dimension-checked quantities.
+//!
+//! [`Quantity`] wraps an [`EngUnit`] and carries its dimension in the type,
+//! so that e.g. a `Quantity<Length>` cannot be passed where a
+//! `Quantity<Mass>` is expected and `Quantity<Force> * Quantity<Length>`
+//! is known to be a `Quantity<Energy>` at compile time.
+//!
+//! Dimensions are type-level exponents ([`Dim`]), so any product or
+//! quotient of quantities is checked, not only the named dimensions below:
+//!
+//! ```
+//! use eng_units::quantity::{Area, Power, Quantity};
+//!
+//! let p = Quantity::<Power>::from_si(500.0);
+//! let a = Quantity::<Area>::from_si(2.0);
+//! let flux = p / a.clone();
+//! assert_eq!("250.00 kg/s^3", flux.to_string());
+//! let p: Quantity<Power> = flux * a;
+//! assert_eq!(500.0, p.value());
+//! ```
+
+use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
+use crate::units::electric_current_unit::ElectricCurrentUnit;
+use crate::units::length_unit::LengthUnit;
+use crate::units::luminous_intensity_unit::LuminousIntensityUnit;
+use crate::units::mass_unit::MassUnit;
+use crate::units::temperature_unit::TemperatureDeltaUnit;
+use crate::units::time_unit::TimeUnit;
+use crate::units::IsEngUnitType;
+use crate::EngUnit;
+use crate::EngUnitError;
+
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops;
+
+pub use typenum;
+use typenum::{Diff, Integer, Sum, N1, N2, N3, P1, P2, P3, Z0};
+
+/// Exponents of the seven SI base dimensions of a quantity.
+pub trait Dimension {
+    const AMOUNT_OF_SUBSTANCE: i32;
+    const ELECTRIC_CURRENT: i32;
+    const LENGTH: i32;
+    const LUMINOUS_INTENSITY: i32;
+    const MASS: i32;
+    const TEMPERATURE: i32;
+    const TIME: i32;
+}
+
+/// A dimension with the exponents of amount of substance, electric
+/// current, length, luminous intensity, mass, temperature and time given as
+/// [`typenum`] integers, e.g. `Dim<Z0, Z0, P1, Z0, Z0, Z0, N1>` for m/s.
+///
+/// Multiplying or dividing two quantities adds or subtracts the exponents,
+/// so every product of dimensions has a type, named or not.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dim<N, I, L, J, M, Th, T>(PhantomData<(N, I, L, J, M, Th, T)>);
+
+impl<N, I, L, J, M, Th, T> Dimension for Dim<N, I, L, J, M, Th, T>
+where
+    N: Integer,
+    I: Integer,
+    L: Integer,
+    J: Integer,
+    M: Integer,
+    Th: Integer,
+    T: Integer,
+{
+    const AMOUNT_OF_SUBSTANCE: i32 = N::I32;
+    const ELECTRIC_CURRENT: i32 = I::I32;
+    const LENGTH: i32 = L::I32;
+    const LUMINOUS_INTENSITY: i32 = J::I32;
+    const MASS: i32 = M::I32;
+    const TEMPERATURE: i32 = Th::I32;
+    const TIME: i32 = T::I32;
+}
+
+/// Dimension of the product of two quantities.
+pub trait DimensionMul<Rhs> {
+    type Output: Dimension;
+}
+
+/// Dimension of the quotient of two quantities.
+pub trait DimensionDiv<Rhs> {
+    type Output: Dimension;
+}
+
+macro_rules! dimension_op {
+    ($op_trait:ident, $int_op:ident, $result:ident) => {
+        impl<N1, I1, L1, J1, M1, Th1, T1, N2, I2, L2, J2, M2, Th2, T2>
+            $op_trait<Dim<N2, I2, L2, J2, M2, Th2, T2>> for Dim<N1, I1, L1, J1, M1, Th1, T1>
+        where
+            N1: ops::$int_op<N2>,
+            I1: ops::$int_op<I2>,
+            L1: ops::$int_op<L2>,
+            J1: ops::$int_op<J2>,
+            M1: ops::$int_op<M2>,
+            Th1: ops::$int_op<Th2>,
+            T1: ops::$int_op<T2>,
+            $result<N1, N2>: Integer,
+            $result<I1, I2>: Integer,
+            $result<L1, L2>: Integer,
+            $result<J1, J2>: Integer,
+            $result<M1, M2>: Integer,
+            $result<Th1, Th2>: Integer,
+            $result<T1, T2>: Integer,
+        {
+            type Output = Dim<
+                $result<N1, N2>,
+                $result<I1, I2>,
+                $result<L1, L2>,
+                $result<J1, J2>,
+                $result<M1, M2>,
+                $result<Th1, Th2>,
+                $result<T1, T2>,
+            >;
+        }
+    };
+}
+
+dimension_op!(DimensionMul, Add, Sum);
+dimension_op!(DimensionDiv, Sub, Diff);
+
+/// Pure number
+pub type Dimensionless = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// mol
+pub type AmountOfSubstance = Dim<P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// A
+pub type ElectricCurrent = Dim<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+/// m
+pub type Length = Dim<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+/// cd
+pub type LuminousIntensity = Dim<Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+/// kg
+pub type Mass = Dim<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+/// K (temperature difference)
+pub type TemperatureDelta = Dim<Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+/// s
+pub type Time = Dim<Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+/// m^2
+pub type Area = Dim<Z0, Z0, P2, Z0, Z0, Z0, Z0>;
+/// m^3
+pub type Volume = Dim<Z0, Z0, P3, Z0, Z0, Z0, Z0>;
+/// m/s
+pub type Velocity = Dim<Z0, Z0, P1, Z0, Z0, Z0, N1>;
+/// m/s^2
+pub type Acceleration = Dim<Z0, Z0, P1, Z0, Z0, Z0, N2>;
+/// 1/s
+pub type Frequency = Dim<Z0, Z0, Z0, Z0, Z0, Z0, N1>;
+/// kg/m^3
+pub type Density = Dim<Z0, Z0, N3, Z0, P1, Z0, Z0>;
+/// kg·m/s^2
+pub type Force = Dim<Z0, Z0, P1, Z0, P1, Z0, N2>;
+/// kg/m·s^2
+pub type Pressure = Dim<Z0, Z0, N1, Z0, P1, Z0, N2>;
+/// kg·m^2/s^2
+pub type Energy = Dim<Z0, Z0, P2, Z0, P1, Z0, N2>;
+/// kg·m^2/s^3
+pub type Power = Dim<Z0, Z0, P2, Z0, P1, Z0, N3>;
+/// A·s
+pub type ElectricCharge = Dim<Z0, P1, Z0, Z0, Z0, Z0, P1>;
+/// kg·m^2/A·s^3
+pub type ElectricPotential = Dim<Z0, N1, P2, Z0, P1, Z0, N3>;
+/// kg·m^2/A^2·s^3
+pub type ElectricResistance = Dim<Z0, N2, P2, Z0, P1, Z0, N3>;
+
+/// An [`EngUnit`] whose dimension `D` is checked at compile time.
+#[derive(Clone, Debug)]
+pub struct Quantity<D: Dimension> {
+    unit: EngUnit,
+    dimension: PhantomData<D>,
+}
+
+impl<D: Dimension> Quantity<D> {
+    /// Creates a quantity from a value expressed in SI base units.
+    pub fn from_si(value: f64) -> Self {
+        let mut unit = EngUnit::new();
+        unit.value = value;
+        unit.amount_of_substance_count = D::AMOUNT_OF_SUBSTANCE;
+        unit.electric_current_count = D::ELECTRIC_CURRENT;
+        unit.length_count = D::LENGTH;
+        unit.luminous_intensity_count = D::LUMINOUS_INTENSITY;
+        unit.mass_count = D::MASS;
+        unit.temperature_count = D::TEMPERATURE;
+        unit.time_count = D::TIME;
+        if D::AMOUNT_OF_SUBSTANCE != 0 {
+            unit.amount_of_substance_unit = AmountOfSubstanceUnit::Mol;
+        }
+        if D::ELECTRIC_CURRENT != 0 {
+            unit.electric_current_unit = ElectricCurrentUnit::Ampere;
+        }
+        if D::LENGTH != 0 {
+            unit.length_unit = LengthUnit::Meter;
+        }
+        if D::LUMINOUS_INTENSITY != 0 {
+            unit.luminous_intensity_unit = LuminousIntensityUnit::Candela;
+        }
+        if D::MASS != 0 {
+            unit.mass_unit = MassUnit::Kilogram;
+        }
+        if D::TEMPERATURE != 0 {
+            unit.temperature_unit = TemperatureDeltaUnit::K;
+        }
+        if D::TIME != 0 {
+            unit.time_unit = TimeUnit::Second;
+        }
+        Self::wrap(unit)
+    }
+
+    fn wrap(unit: EngUnit) -> Self {
+        Self {
+            unit,
+            dimension: PhantomData,
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.unit.value
+    }
+
+    pub fn as_eng_unit(&self) -> &EngUnit {
+        &self.unit
+    }
+
+    pub fn into_eng_unit(self) -> EngUnit {
+        self.unit
+    }
+
+    pub fn to_si_units(&self) -> Self {
+        Self::wrap(self.unit.to_si_units())
+    }
+
+    pub fn convert<
+        T: IsEngUnitType
+            + Into<AmountOfSubstanceUnit>
+            + Into<ElectricCurrentUnit>
+            + Into<LengthUnit>
+            + Into<LuminousIntensityUnit>
+            + Into<MassUnit>
+            + Into<TemperatureDeltaUnit>
+            + Into<TimeUnit>,
+    >(
+        &self,
+        to_unit: T,
+    ) -> Self {
+        Self::wrap(self.unit.convert(to_unit))
+    }
+
+    /// Returns `true` if the total dimension of `unit`, including any
+    /// complex units, matches `D`.
+    pub fn matches(unit: &EngUnit) -> bool {
+        let si = unit.to_si_units();
+        si.amount_of_substance_count == D::AMOUNT_OF_SUBSTANCE
+            && si.electric_current_count == D::ELECTRIC_CURRENT
+            && si.length_count == D::LENGTH
+            && si.luminous_intensity_count == D::LUMINOUS_INTENSITY
+            && si.mass_count == D::MASS
+            && si.temperature_count == D::TEMPERATURE
+            && si.time_count == D::TIME
+    }
+}
+
+macro_rules! base_quantity_new {
+    ($dimension:ident, $unit_type:ident, $count:ident, $unit:ident) => {
+        impl Quantity<$dimension> {
+            pub fn new(value: f64, unit: $unit_type) -> Self {
+                let mut eng_unit = EngUnit::new();
+                eng_unit.value = value;
+                eng_unit.$count = 1;
+                eng_unit.$unit = unit;
+                Self::wrap(eng_unit)
+            }
+        }
+    };
+}
+
+base_quantity_new!(
+    AmountOfSubstance,
+    AmountOfSubstanceUnit,
+    amount_of_substance_count,
+    amount_of_substance_unit
+);
+base_quantity_new!(
+    ElectricCurrent,
+    ElectricCurrentUnit,
+    electric_current_count,
+    electric_current_unit
+);
+base_quantity_new!(Length, LengthUnit, length_count, length_unit);
+base_quantity_new!(
+    LuminousIntensity,
+    LuminousIntensityUnit,
+    luminous_intensity_count,
+    luminous_intensity_unit
+);
+base_quantity_new!(Mass, MassUnit, mass_count, mass_unit);
+base_quantity_new!(
+    TemperatureDelta,
+    TemperatureDeltaUnit,
+    temperature_count,
+    temperature_unit
+);
+base_quantity_new!(Time, TimeUnit, time_count, time_unit);
+
+impl<D: Dimension> Display for Quantity<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.unit.fmt(f)
+    }
+}
+
+impl<D: Dimension> From<Quantity<D>> for EngUnit {
+    fn from(value: Quantity<D>) -> Self {
+        value.unit
+    }
+}
+
+impl<D: Dimension> TryFrom<EngUnit> for Quantity<D> {
+    type Error = EngUnitError;
+
+    fn try_from(value: EngUnit) -> Result<Self, Self::Error> {
+        if Self::matches(&value) {
+            Ok(Self::wrap(value))
+        } else {
+            Err(EngUnitError::DimensionMismatch {
+                left: Self::from_si(1.0).unit.unit_to_string(),
+                right: value.unit_to_string(),
+            })
+        }
+    }
+}
+
+impl<D: Dimension> ops::Mul<f64> for Quantity<D> {
+    type Output = Quantity<D>;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::wrap(self.unit * rhs)
+    }
+}
+
+impl<D: Dimension> ops::Mul<Quantity<D>> for f64 {
+    type Output = Quantity<D>;
+    fn mul(self, rhs: Quantity<D>) -> Self::Output {
+        Quantity::wrap(self * rhs.unit)
+    }
+}
+
+impl<D: Dimension> ops::Div<f64> for Quantity<D> {
+    type Output = Quantity<D>;
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::wrap(self.unit * (1.0 / rhs))
+    }
+}
+
+impl<D: Dimension> ops::Add for Quantity<D> {
+    type Output = Quantity<D>;
+    fn add(self, rhs: Quantity<D>) -> Self::Output {
+        Self::wrap(self.unit + rhs.unit)
+    }
+}
+
+impl<D: Dimension> ops::Sub for Quantity<D> {
+    type Output = Quantity<D>;
+    fn sub(self, rhs: Quantity<D>) -> Self::Output {
+        Self::wrap(self.unit - rhs.unit)
+    }
+}
+
+impl<D: Dimension> ops::Neg for Quantity<D> {
+    type Output = Quantity<D>;
+    fn neg(self) -> Self::Output {
+        Self::wrap(-self.unit)
+    }
+}
+
+impl<Dl, Dr> ops::Mul<Quantity<Dr>> for Quantity<Dl>
+where
+    Dl: Dimension + DimensionMul<Dr>,
+    Dr: Dimension,
+{
+    type Output = Quantity<<Dl as DimensionMul<Dr>>::Output>;
+    fn mul(self, rhs: Quantity<Dr>) -> Self::Output {
+        Quantity::wrap(self.unit * rhs.unit)
+    }
+}
+
+impl<Dl, Dr> ops::Div<Quantity<Dr>> for Quantity<Dl>
+where
+    Dl: Dimension + DimensionDiv<Dr>,
+    Dr: Dimension,
+{
+    type Output = Quantity<<Dl as DimensionDiv<Dr>>::Output>;
+    fn div(self, rhs: Quantity<Dr>) -> Self::Output {
+        Quantity::wrap(self.unit / rhs.unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex_units::energy_unit::{JOULE, KILOJOULE};
+    use crate::complex_units::*;
+    use crate::testing::assert_close;
+    use crate::units::mass_unit::KILOGRAM_TO_POUND;
+    use crate::{kJ, mass};
+
+    #[test]
+    fn base_constructor() {
+        let l = Quantity::<Length>::new(2.0, LengthUnit::Meter);
+        assert_eq!(2.0, l.value());
+        assert_eq!("2.00 m", l.to_string());
+        assert_eq!(1, l.as_eng_unit().length_count);
+    }
+
+    #[test]
+    fn from_si() {
+        let p = Quantity::<Power>::from_si(5.0);
+        assert_eq!("5.00 kg·m^2/s^3", p.to_string());
+        let f = Quantity::<Frequency>::from_si(60.0);
+        assert_eq!("60.00 /s", f.to_string());
+    }
+
+    #[test]
+    fn multiply_to_derived() {
+        let m = Quantity::<Mass>::new(2.0, MassUnit::Kilogram);
+        let a = Quantity::<Acceleration>::from_si(3.0);
+        let f: Quantity<Force> = m * a;
+        let d = Quantity::<Length>::new(4.0, LengthUnit::Meter);
+        let e: Quantity<Energy> = f * d;
+        assert_eq!(24.0, e.value());
+        assert_eq!("24.00 kg·m^2/s^2", e.to_string());
+    }
+
+    #[test]
+    fn divide_to_derived() {
+        let e = Quantity::<Energy>::from_si(100.0);
+        let t = Quantity::<Time>::new(4.0, TimeUnit::Second);
+        let p: Quantity<Power> = e / t;
+        assert_eq!(25.0, p.value());
+    }
+
+    #[test]
+    fn unnamed_dimensions() {
+        let e = Quantity::<Energy>::from_si(3000.0);
+        let m = Quantity::<Mass>::new(2.0, MassUnit::Kilogram);
+        let specific: Quantity<Dim<Z0, Z0, P2, Z0, Z0, Z0, N2>> = e / m;
+        assert_eq!(1500.0, specific.value());
+        let v = Quantity::<Velocity>::from_si(10.0);
+        let specific = specific + v.clone() * v;
+        assert_eq!(1600.0, specific.value());
+        assert_eq!(-2, specific.as_eng_unit().time_count);
+
+        let p = Quantity::<Power>::from_si(500.0);
+        let a = Quantity::<Area>::from_si(2.0);
+        let flux = p / a.clone();
+        assert_eq!(250.0, flux.value());
+        let p: Quantity<Power> = flux * a;
+        assert_eq!(500.0, p.value());
+    }
+
+    #[test]
+    fn divide_same_dimension() {
+        let a = Quantity::<Length>::new(3.0, LengthUnit::Meter);
+        let b = Quantity::<Length>::new(1.5, LengthUnit::Meter);
+        let ratio: Quantity<Dimensionless> = a / b;
+        assert_eq!(2.0, ratio.value());
+        assert_eq!("2.00", ratio.to_string());
+    }
+
+    #[test]
+    fn scalar_operations() {
+        let l = Quantity::<Length>::new(3.0, LengthUnit::Meter);
+        let l = 2.0 * l * 2.0 / 4.0;
+        assert_eq!(3.0, l.value());
+    }
+
+    #[test]
+    fn add_and_sub() {
+        let a = Quantity::<Length>::new(1.0, LengthUnit::Meter);
+        let b = Quantity::<Length>::new(50.0, LengthUnit::Meter);
+        let c = a + b;
+        assert_eq!(51.0, c.value());
+        let d = -(c - Quantity::<Length>::new(1.0, LengthUnit::Meter));
+        assert_eq!(-50.0, d.value());
+    }
+
+    #[test]
+    fn try_from_eng_unit() {
+        let m = mass!(1.5, MassUnit::Pound);
+        let q = Quantity::<Mass>::try_from(m).unwrap();
+        assert_eq!(1.5, q.value());
+        assert_eq!(MassUnit::Pound, q.as_eng_unit().mass_unit);
+
+        let m = mass!(1.5, MassUnit::Pound);
+        let err = Quantity::<Length>::try_from(m).unwrap_err();
+        assert_eq!(
+            EngUnitError::DimensionMismatch {
+                left: "m".to_string(),
+                right: "lb".to_string()
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn try_from_complex_unit() {
+        let e = kJ!(2.0);
+        let q = Quantity::<Energy>::try_from(e).unwrap();
+        assert_eq!("2.00 kJ", q.to_string());
+        assert_eq!(vec![KILOJOULE], q.as_eng_unit().unit_numerator);
+        assert!(Quantity::<Power>::try_from(q.into_eng_unit()).is_err());
+    }
+
+    #[test]
+    fn round_trip_eng_unit() {
+        let mut unit = EngUnit::new();
+        unit.value = 7.0;
+        unit.mass_count = 1;
+        unit.mass_unit = MassUnit::Kilogram;
+        unit.length_count = 2;
+        unit.length_unit = LengthUnit::Meter;
+        unit.time_count = -2;
+        unit.time_unit = TimeUnit::Second;
+        let unit = push_complex_numerator(&unit, JOULE).unwrap();
+        let q = Quantity::<Energy>::try_from(unit.clone()).unwrap();
+        let back: EngUnit = q.into();
+        assert_eq!(unit.value, back.value);
+        assert_eq!(unit.unit_numerator, back.unit_numerator);
+        assert_eq!(unit.to_string(), back.to_string());
+    }
+
+    #[test]
+    fn convert_keeps_dimension() {
+        let m = Quantity::<Mass>::new(1.0, MassUnit::Kilogram);
+        let lb = m.convert(MassUnit::Pound);
+        assert_eq!(MassUnit::Pound, lb.as_eng_unit().mass_unit);
+        assert_close(KILOGRAM_TO_POUND, lb.value());
+        let si = lb.to_si_units();
+        assert_close(1.0, si.value());
+    }
+}
@@ -0,0 +1,299 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::complex_units::ComplexUnit;
+use crate::error::{check_finite, EngUnitError};
+use crate::parser::parse_unit_with;
+use crate::EngUnit;
+
+use std::sync::Mutex;
+
+/// Symbols of every unit defined so far, shared by all registries.
+static SYMBOLS: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+/// A `'static` copy of `symbol`. [`ComplexUnit`] holds a `&'static str`,
+/// so each distinct symbol is leaked once, the first time it is defined.
+fn intern(symbol: String) -> &'static str {
+    let mut symbols = SYMBOLS.lock().unwrap_or_else(|e| e.into_inner());
+    match symbols.iter().find(|s| **s == symbol) {
+        Some(s) => s,
+        None => {
+            let s: &'static str = Box::leak(symbol.into_boxed_str());
+            symbols.push(s);
+            s
+        }
+    }
+}
+
+/// Returns `true` for symbols the parser reads as a single unit: a letter
+/// or `°` followed by letters, digits and `_`, e.g. `bbl` or `inH2O`.
+fn is_symbol(symbol: &str) -> bool {
+    let mut chars = symbol.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '°' => chars.all(|c| c.is_alphanumeric() || c == '_'),
+        _ => false,
+    }
+}
+
+/// Named units defined at runtime, e.g. from a configuration file.
+///
+/// ```
+/// use eng_units::UnitRegistry;
+///
+/// let mut registry = UnitRegistry::new();
+/// registry.define("bbl", "m^3", 0.158_987_294_928).unwrap();
+/// let volume = registry.parse("2 bbl").unwrap();
+/// assert_eq!("2.00 bbl", volume.to_string());
+/// assert_eq!("0.32 m^3", registry.convert(&volume, "m^3").unwrap().to_string());
+/// ```
+///
+/// Units of a registry display like catalog units once created, but only
+/// the registry's own [`UnitRegistry::parse`], [`UnitRegistry::simplify`]
+/// and, with the `serde` feature, `UnitRegistry::deserialize` know their
+/// symbols. `str::parse` and [`EngUnit::simplify`] only know the catalog.
+///
+/// Symbols outlive the registry: each distinct symbol is allocated once for
+/// the life of the process and shared by every registry that defines it.
+/// Registries are meant to be filled once at startup, not per request.
+#[derive(Clone, Debug, Default)]
+pub struct UnitRegistry {
+    units: Vec<ComplexUnit>,
+}
+
+impl UnitRegistry {
+    pub fn new() -> Self {
+        UnitRegistry { units: Vec::new() }
+    }
+
+    /// Defines `symbol` as `scale` times `definition`, where `definition` is
+    /// a unit expression in catalog units or units defined earlier, e.g.
+    /// `define("SCFM", "ft^3/min", 1.0)`. `scale` must be positive.
+    pub fn define(
+        &mut self,
+        symbol: impl Into<String>,
+        definition: &str,
+        scale: f64,
+    ) -> Result<ComplexUnit, EngUnitError> {
+        let symbol = symbol.into();
+        if !is_symbol(&symbol) {
+            return Err(EngUnitError::InvalidSymbol(symbol));
+        }
+        if !(scale.is_finite() && scale > 0.0) {
+            return Err(EngUnitError::InvalidScale(scale));
+        }
+        if self.parse(&format!("1 {symbol}")).is_ok() {
+            return Err(EngUnitError::DuplicateUnit(symbol));
+        }
+
+        let mut unit = self.parse(&format!("1 {definition}"))?;
+        unit.value = scale;
+        let si = unit.to_si_units();
+        let complex = ComplexUnit {
+            prefix_multiplier: check_finite(1.0 / si.value)?,
+            amount_of_substance_count: si.amount_of_substance_count,
+            amount_of_substance_unit: si.amount_of_substance_unit,
+            electric_current_count: si.electric_current_count,
+            electric_current_unit: si.electric_current_unit,
+            length_count: si.length_count,
+            length_unit: si.length_unit,
+            luminous_intensity_count: si.luminous_intensity_count,
+            luminous_intensity_unit: si.luminous_intensity_unit,
+            mass_count: si.mass_count,
+            mass_unit: si.mass_unit,
+            temperature_count: si.temperature_count,
+            temperature_unit: si.temperature_unit,
+            time_count: si.time_count,
+            time_unit: si.time_unit,
+            unit_string: intern(symbol),
+        };
+        self.units.push(complex);
+        Ok(complex)
+    }
+
+    /// Looks up a unit defined in this registry.
+    pub fn get(&self, symbol: &str) -> Option<ComplexUnit> {
+        self.units
+            .iter()
+            .find(|complex| complex.unit_string == symbol)
+            .copied()
+    }
+
+    /// The units defined so far, in order of definition.
+    pub fn units(&self) -> &[ComplexUnit] {
+        &self.units
+    }
+
+    /// [`crate::parse_unit`] that also knows the units of this registry.
+    pub fn parse(&self, s: &str) -> Result<EngUnit, EngUnitError> {
+        parse_unit_with(s, &self.units)
+    }
+
+    /// Expresses `unit` in `target`, a unit expression without a value such
+    /// as `"bbl/d"`.
+    pub fn convert(&self, unit: &EngUnit, target: &str) -> Result<EngUnit, EngUnitError> {
+        unit.convert_to(&self.parse(&format!("1 {target}"))?)
+    }
+
+    /// [`EngUnit::simplify`] followed by the units of this registry.
+    ///
+    /// Catalog units come first. A registry unit is then only used where it
+    /// is exactly the base units it replaces, so `ft^3/min` becomes `SCFM`
+    /// but `m^3` does not become `bbl`.
+    pub fn simplify(&self, unit: &EngUnit) -> EngUnit {
+        unit.simplify().simplify_with(&self.units, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::EngUnitError;
+    use crate::registry::UnitRegistry;
+    use crate::testing::assert_close;
+    use crate::units::length_unit::LengthUnit;
+    use crate::units::time_unit::TimeUnit;
+    use crate::{length, time, EngUnit};
+
+    const BARREL_TO_CUBIC_METERS: f64 = 0.158_987_294_928;
+    const CUBIC_FOOT_TO_CUBIC_METERS: f64 = 0.028_316_846_592;
+
+    fn registry() -> UnitRegistry {
+        let mut registry = UnitRegistry::new();
+        registry
+            .define("bbl", "m^3", BARREL_TO_CUBIC_METERS)
+            .unwrap();
+        registry.define("SCFM", "ft^3/min", 1.0).unwrap();
+        registry.define("MMBtu", "kJ", 1_055_055.852_62).unwrap();
+        registry
+    }
+
+    #[test]
+    fn define() {
+        let registry = registry();
+        assert_eq!(3, registry.units().len());
+        let bbl = registry.get("bbl").unwrap();
+        assert_eq!("bbl", bbl.unit_string);
+        assert_eq!(3, bbl.length_count);
+        assert_eq!(LengthUnit::Meter, bbl.length_unit);
+        assert_close(1.0 / BARREL_TO_CUBIC_METERS, bbl.prefix_multiplier);
+        assert!(registry.get("gal").is_none());
+    }
+
+    #[test]
+    fn symbols_are_shared() {
+        let a = registry().get("bbl").unwrap();
+        let b = registry().get("bbl").unwrap();
+        assert!(std::ptr::eq(a.unit_string, b.unit_string));
+        let mut registry = UnitRegistry::new();
+        assert!(registry.define("inH2O_60F", "Pa", 248.84).is_ok());
+    }
+
+    #[test]
+    fn define_from_registered_unit() {
+        let mut registry = registry();
+        registry.define("Mbbl", "bbl", 1000.0).unwrap();
+        let unit = registry.parse("1 Mbbl").unwrap();
+        let unit = registry.convert(&unit, "m^3").unwrap();
+        assert_close(1000.0 * BARREL_TO_CUBIC_METERS, unit.value);
+    }
+
+    #[test]
+    fn define_errors() {
+        let mut registry = registry();
+        assert_eq!(
+            Err(EngUnitError::DuplicateUnit("bbl".to_string())),
+            registry.define("bbl", "m^3", 0.16)
+        );
+        assert_eq!(
+            Err(EngUnitError::DuplicateUnit("kJ".to_string())),
+            registry.define("kJ", "J", 1000.0)
+        );
+        assert_eq!(
+            Err(EngUnitError::InvalidSymbol("m/s".to_string())),
+            registry.define("m/s", "m", 1.0)
+        );
+        assert_eq!(
+            Err(EngUnitError::InvalidSymbol(String::new())),
+            registry.define("", "m", 1.0)
+        );
+        assert_eq!(
+            Err(EngUnitError::UnknownUnit("furlong".to_string())),
+            registry.define("chain", "furlong", 0.1)
+        );
+        assert_eq!(
+            Err(EngUnitError::InvalidSymbol("-".to_string())),
+            registry.define("-", "m", 1.0)
+        );
+        assert_eq!(
+            Err(EngUnitError::InvalidSymbol("2x".to_string())),
+            registry.define("2x", "m", 1.0)
+        );
+        assert_eq!(
+            Err(EngUnitError::InvalidScale(-1.0)),
+            registry.define("neg", "m", -1.0)
+        );
+        assert_eq!(
+            Err(EngUnitError::InvalidScale(0.0)),
+            registry.define("nothing", "m", 0.0)
+        );
+        assert!(registry.define("huge", "m", f64::INFINITY).is_err());
+        assert_eq!(
+            "unit 'bbl' is already defined",
+            registry.define("bbl", "m^3", 0.16).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let registry = registry();
+        let unit = registry.parse("3 bbl/d").unwrap();
+        assert_eq!("3.00 bbl/d", unit.to_string());
+        let unit = registry.parse("2.5 MMBtu/hr").unwrap();
+        assert_eq!("2.50 MMBtu/hr", unit.to_string());
+        assert!("3 bbl".parse::<EngUnit>().is_err());
+    }
+
+    #[test]
+    fn convert() {
+        let registry = registry();
+        let unit = registry.parse("10 m^3/hr").unwrap();
+        let scfm = registry.convert(&unit, "SCFM").unwrap();
+        assert_eq!("SCFM", scfm.unit_to_string());
+        assert_close(10.0 / CUBIC_FOOT_TO_CUBIC_METERS / 60.0, scfm.value);
+
+        let unit = registry.parse("1 MMBtu").unwrap();
+        let unit = registry.convert(&unit, "kJ").unwrap();
+        assert_close(1_055_055.852_62, unit.value);
+
+        let unit = registry.parse("1 bbl").unwrap();
+        assert!(registry.convert(&unit, "kg").is_err());
+    }
+
+    #[test]
+    fn simplify() {
+        let registry = registry();
+        let l = length!(2.0, LengthUnit::Foot);
+        let t = time!(1.0, TimeUnit::Minute);
+        let unit = l.clone() * l.clone() * l / t;
+        let unit = registry.simplify(&unit);
+        assert_eq!("SCFM", unit.unit_to_string());
+        assert_close(8.0, unit.value);
+
+        let unit = registry.parse("5 kg·m^2/s^2").unwrap();
+        assert_eq!("5.00 J", registry.simplify(&unit).to_string());
+        let unit = registry.parse("2 m^3").unwrap();
+        assert_eq!("2.00 m^3", registry.simplify(&unit).to_string());
+    }
+}
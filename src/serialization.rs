@@ -0,0 +1,435 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! `serde` support, enabled with the `serde` feature.
+//!
+//! [`EngUnit`] serializes to the compact string form, e.g. `"12.3 kJ/kg"`,
+//! and is read back with the parser. Use the [`structured`] module with
+//! `#[serde(with = "eng_units::serialization::structured")]` for a map with
+//! the value and a unit and exponent for every dimension instead. Unit enums
+//! and [`ComplexUnit`] serialize to their symbols.
+//!
+//! Units of a [`UnitRegistry`] are written in the compact form like catalog
+//! units and read back with [`UnitRegistry::deserialize`], or with the
+//! registry as a [`DeserializeSeed`]. The structured form only holds catalog
+//! units.
+
+use crate::complex_units::{find_complex_unit, ComplexUnit};
+use crate::units::absolute_temperature_unit::AbsoluteTemperatureUnit;
+use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
+use crate::units::electric_current_unit::ElectricCurrentUnit;
+use crate::units::length_unit::LengthUnit;
+use crate::units::luminous_intensity_unit::LuminousIntensityUnit;
+use crate::units::mass_unit::MassUnit;
+use crate::units::temperature_unit::TemperatureDeltaUnit;
+use crate::units::time_unit::TimeUnit;
+use crate::{EngUnit, UnitRegistry};
+
+use serde::de::{DeserializeSeed, Error};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes a unit enum as its symbol. The `None` variant is written as an
+/// empty string.
+macro_rules! symbol_serde {
+    ($unit:ident $(, $none:ident)?) => {
+        impl Serialize for $unit {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.to_string())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $unit {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                $(
+                    if s.is_empty() {
+                        return Ok($unit::$none);
+                    }
+                )?
+                $unit::from_string(&s)
+                    .ok_or_else(|| D::Error::custom(format!("unknown unit '{}'", s)))
+            }
+        }
+    };
+}
+
+symbol_serde!(AbsoluteTemperatureUnit);
+symbol_serde!(AmountOfSubstanceUnit, None);
+symbol_serde!(ElectricCurrentUnit, None);
+symbol_serde!(LengthUnit, None);
+symbol_serde!(LuminousIntensityUnit, None);
+symbol_serde!(MassUnit, None);
+symbol_serde!(TemperatureDeltaUnit, None);
+symbol_serde!(TimeUnit, None);
+
+/// Units defined in a [`UnitRegistry`] are a serialization error, since
+/// [`ComplexUnit`]'s `Deserialize` only knows the catalog.
+impl Serialize for ComplexUnit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if find_complex_unit(self.unit_string) != Some(*self) {
+            return Err(serde::ser::Error::custom(format!(
+                "unit '{}' is not in the catalog",
+                self.unit_string
+            )));
+        }
+        serializer.serialize_str(self.unit_string)
+    }
+}
+
+/// Only units in [`crate::complex_units::COMPLEX_UNITS`] can be read back,
+/// since the symbol is all that is stored.
+impl<'de> Deserialize<'de> for ComplexUnit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        find_complex_unit(&s).ok_or_else(|| D::Error::custom(format!("unknown unit '{}'", s)))
+    }
+}
+
+/// Fails for `NaN` and infinite values, which have no representation that
+/// reads back.
+fn check_finite<E: serde::ser::Error>(value: f64) -> Result<f64, E> {
+    crate::error::check_finite(value).map_err(E::custom)
+}
+
+/// The value is written with full precision so that it reads back exactly.
+/// `NaN` and infinite values are a serialization error.
+impl Serialize for EngUnit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        check_finite(self.value)?;
+        let units = self.unit_to_string();
+        if units.is_empty() {
+            serializer.serialize_str(&self.value.to_string())
+        } else {
+            serializer.serialize_str(&format!("{} {}", self.value, units))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EngUnit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
+
+impl UnitRegistry {
+    /// Reads an [`EngUnit`] in the compact form, with the units of this
+    /// registry as well as the catalog.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        &self,
+        deserializer: D,
+    ) -> Result<EngUnit, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        self.parse(&s).map_err(D::Error::custom)
+    }
+}
+
+/// [`UnitRegistry::deserialize`] for a value inside a larger document.
+impl<'de> DeserializeSeed<'de> for &UnitRegistry {
+    type Value = EngUnit;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<EngUnit, D::Error> {
+        UnitRegistry::deserialize(self, deserializer)
+    }
+}
+
+/// Structured form of [`EngUnit`]:
+///
+/// ```toml
+/// value = 12.3
+/// numerator = ["kJ"]
+/// mass = { unit = "kg", exponent = -1 }
+/// ```
+///
+/// Dimensions with a zero exponent and empty lists of named units are left
+/// out. A dimension that is present must have both a unit and a non-zero
+/// exponent.
+pub mod structured {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Dimension<U> {
+        unit: U,
+        exponent: i32,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Structured {
+        value: f64,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        numerator: Vec<ComplexUnit>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        denominator: Vec<ComplexUnit>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        amount_of_substance: Option<Dimension<AmountOfSubstanceUnit>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        electric_current: Option<Dimension<ElectricCurrentUnit>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        length: Option<Dimension<LengthUnit>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        luminous_intensity: Option<Dimension<LuminousIntensityUnit>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        mass: Option<Dimension<MassUnit>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        temperature: Option<Dimension<TemperatureDeltaUnit>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        time: Option<Dimension<TimeUnit>>,
+    }
+
+    fn dimension<U>(unit: U, exponent: i32) -> Option<Dimension<U>> {
+        if exponent == 0 {
+            None
+        } else {
+            Some(Dimension { unit, exponent })
+        }
+    }
+
+    /// The dimension read from `d`, or no dimension if `d` is absent.
+    fn read_dimension<U: PartialEq, E: Error>(
+        name: &str,
+        d: Option<Dimension<U>>,
+        none: U,
+    ) -> Result<(U, i32), E> {
+        match d {
+            None => Ok((none, 0)),
+            Some(d) if d.unit == none || d.exponent == 0 => Err(E::custom(format!(
+                "{name} needs both a unit and a non-zero exponent"
+            ))),
+            Some(d) => Ok((d.unit, d.exponent)),
+        }
+    }
+
+    pub fn serialize<S: Serializer>(unit: &EngUnit, serializer: S) -> Result<S::Ok, S::Error> {
+        Structured {
+            value: check_finite(unit.value)?,
+            numerator: unit.unit_numerator.clone(),
+            denominator: unit.unit_denominator.clone(),
+            amount_of_substance: dimension(
+                unit.amount_of_substance_unit,
+                unit.amount_of_substance_count,
+            ),
+            electric_current: dimension(unit.electric_current_unit, unit.electric_current_count),
+            length: dimension(unit.length_unit, unit.length_count),
+            luminous_intensity: dimension(
+                unit.luminous_intensity_unit,
+                unit.luminous_intensity_count,
+            ),
+            mass: dimension(unit.mass_unit, unit.mass_count),
+            temperature: dimension(unit.temperature_unit, unit.temperature_count),
+            time: dimension(unit.time_unit, unit.time_count),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<EngUnit, D::Error> {
+        let s = Structured::deserialize(deserializer)?;
+        let mut unit = EngUnit::new();
+        unit.value = crate::error::check_finite(s.value).map_err(D::Error::custom)?;
+        unit.unit_numerator = s.numerator;
+        unit.unit_denominator = s.denominator;
+        (
+            unit.amount_of_substance_unit,
+            unit.amount_of_substance_count,
+        ) = read_dimension(
+            "amount_of_substance",
+            s.amount_of_substance,
+            AmountOfSubstanceUnit::None,
+        )?;
+        (unit.electric_current_unit, unit.electric_current_count) = read_dimension(
+            "electric_current",
+            s.electric_current,
+            ElectricCurrentUnit::None,
+        )?;
+        (unit.length_unit, unit.length_count) =
+            read_dimension("length", s.length, LengthUnit::None)?;
+        (unit.luminous_intensity_unit, unit.luminous_intensity_count) = read_dimension(
+            "luminous_intensity",
+            s.luminous_intensity,
+            LuminousIntensityUnit::None,
+        )?;
+        (unit.mass_unit, unit.mass_count) = read_dimension("mass", s.mass, MassUnit::None)?;
+        (unit.temperature_unit, unit.temperature_count) =
+            read_dimension("temperature", s.temperature, TemperatureDeltaUnit::None)?;
+        (unit.time_unit, unit.time_count) = read_dimension("time", s.time, TimeUnit::None)?;
+        Ok(unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::complex_units::energy_unit::KILOJOULE;
+    use crate::complex_units::ComplexUnit;
+    use crate::units::length_unit::LengthUnit;
+    use crate::units::mass_unit::MassUnit;
+    use crate::units::same_units;
+    use crate::units::temperature_unit::TemperatureDeltaUnit;
+    use crate::units::time_unit::TimeUnit;
+    use crate::{kJ, length, mass, temperature, time, EngUnit, UnitRegistry};
+
+    use serde::de::DeserializeSeed;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct Structured {
+        #[serde(with = "crate::serialization::structured")]
+        unit: EngUnit,
+    }
+
+    fn specific_heat() -> EngUnit {
+        kJ!(4.186) / mass!(1.0, MassUnit::Kilogram) / temperature!(1.0, TemperatureDeltaUnit::C)
+    }
+
+    fn acceleration() -> EngUnit {
+        let l = length!(9.81, LengthUnit::Meter);
+        let t = time!(1.0, TimeUnit::Second);
+        l / (t.clone() * t)
+    }
+
+    fn assert_same(expected: &EngUnit, actual: &EngUnit) {
+        assert_eq!(expected.value, actual.value);
+        assert_eq!(expected.unit_to_string(), actual.unit_to_string());
+        assert!(same_units(expected, actual));
+    }
+
+    #[test]
+    fn unit_enums() {
+        assert_eq!("\"ft\"", serde_json::to_string(&LengthUnit::Foot).unwrap());
+        assert_eq!(
+            TemperatureDeltaUnit::C,
+            serde_json::from_str("\"°C\"").unwrap()
+        );
+        assert_eq!(MassUnit::None, serde_json::from_str("\"\"").unwrap());
+        assert!(serde_json::from_str::<TimeUnit>("\"fortnight\"").is_err());
+        assert_eq!("\"kJ\"", serde_json::to_string(&KILOJOULE).unwrap());
+        assert_eq!(
+            KILOJOULE,
+            serde_json::from_str::<ComplexUnit>("\"kJ\"").unwrap()
+        );
+    }
+
+    #[test]
+    fn compact_json() {
+        let unit = specific_heat();
+        let json = serde_json::to_string(&unit).unwrap();
+        assert_eq!("\"4.186 kJ/kg·°C\"", json);
+        assert_same(&unit, &serde_json::from_str(&json).unwrap());
+
+        let unit = acceleration();
+        let json = serde_json::to_string(&unit).unwrap();
+        assert_eq!("\"9.81 m/s^2\"", json);
+        assert_same(&unit, &serde_json::from_str(&json).unwrap());
+
+        let unit = mass!(3e-9, MassUnit::Kilogram);
+        assert_same(
+            &unit,
+            &serde_json::from_str(&serde_json::to_string(&unit).unwrap()).unwrap(),
+        );
+        assert!(serde_json::from_str::<EngUnit>("\"1 furlong\"").is_err());
+    }
+
+    #[test]
+    fn compact_toml() {
+        #[derive(Serialize, Deserialize)]
+        struct Input {
+            cp: EngUnit,
+        }
+        let input = Input {
+            cp: specific_heat(),
+        };
+        let s = toml::to_string(&input).unwrap();
+        assert_eq!("cp = \"4.186 kJ/kg·°C\"\n", s);
+        let read: Input = toml::from_str(&s).unwrap();
+        assert_same(&input.cp, &read.cp);
+    }
+
+    #[test]
+    fn structured_json() {
+        let s = Structured {
+            unit: specific_heat(),
+        };
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(
+            "{\"unit\":{\"value\":4.186,\"numerator\":[\"kJ\"],\
+             \"mass\":{\"unit\":\"kg\",\"exponent\":-1},\
+             \"temperature\":{\"unit\":\"°C\",\"exponent\":-1}}}",
+            json
+        );
+        let read: Structured = serde_json::from_str(&json).unwrap();
+        assert_same(&s.unit, &read.unit);
+    }
+
+    #[test]
+    fn structured_toml() {
+        let s = Structured {
+            unit: acceleration(),
+        };
+        let read: Structured = toml::from_str(&toml::to_string(&s).unwrap()).unwrap();
+        assert_same(&s.unit, &read.unit);
+
+        let read: Structured = toml::from_str(
+            "[unit]\n\
+             value = 2.5\n\
+             denominator = [\"kW\"]\n\
+             time = { unit = \"hr\", exponent = -1 }\n",
+        )
+        .unwrap();
+        assert_eq!("2.50 /kW·hr", read.unit.to_string());
+    }
+
+    #[test]
+    fn non_finite_values() {
+        let mut unit = acceleration();
+        unit.value = f64::NAN;
+        assert!(serde_json::to_string(&unit).is_err());
+        unit.value = f64::INFINITY;
+        assert!(serde_json::to_string(&unit).is_err());
+        assert!(serde_json::to_string(&Structured { unit }).is_err());
+    }
+
+    #[test]
+    fn structured_inconsistent_dimensions() {
+        let read = |json: &str| serde_json::from_str::<Structured>(json);
+        assert!(
+            read("{\"unit\":{\"value\":1,\"length\":{\"unit\":\"\",\"exponent\":2}}}").is_err()
+        );
+        assert!(
+            read("{\"unit\":{\"value\":1,\"length\":{\"unit\":\"m\",\"exponent\":0}}}").is_err()
+        );
+        let unit = read("{\"unit\":{\"value\":1,\"length\":{\"unit\":\"m\",\"exponent\":2}}}")
+            .unwrap()
+            .unit;
+        assert_eq!("1.00 m^2", unit.to_string());
+    }
+
+    #[test]
+    fn registry_units() {
+        let mut registry = UnitRegistry::new();
+        registry.define("bbl", "m^3", 0.158_987_294_928).unwrap();
+        let unit = registry.parse("3 bbl/d").unwrap();
+        let json = serde_json::to_string(&unit).unwrap();
+        assert_eq!("\"3 bbl/d\"", json);
+        assert!(serde_json::from_str::<EngUnit>(&json).is_err());
+
+        let mut de = serde_json::Deserializer::from_str(&json);
+        assert_same(&unit, &registry.deserialize(&mut de).unwrap());
+        let mut de = serde_json::Deserializer::from_str(&json);
+        let seed = DeserializeSeed::deserialize(&registry, &mut de).unwrap();
+        assert_same(&unit, &seed);
+
+        assert!(serde_json::to_string(&registry.get("bbl").unwrap()).is_err());
+        assert!(serde_json::to_string(&Structured { unit }).is_err());
+    }
+}
@@ -0,0 +1,326 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::complex_units::capacitance_unit::FARAD;
+use crate::complex_units::electric_charge_unit::COULOMB;
+use crate::complex_units::electric_potential_unit::VOLT;
+use crate::complex_units::electric_resistance_unit::OHM;
+use crate::complex_units::energy_unit::{BTU, ERG, JOULE};
+use crate::complex_units::force_unit::{DYNE, NEWTON, POUND_FORCE};
+use crate::complex_units::frequency_unit::HERTZ;
+use crate::complex_units::inductance_unit::HENRY;
+use crate::complex_units::magnetic_flux_density_unit::TESLA;
+use crate::complex_units::magnetic_flux_unit::WEBER;
+use crate::complex_units::power_unit::{HORSEPOWER, WATT};
+use crate::complex_units::pressure_unit::{BARYE, PASCAL, PSI};
+use crate::complex_units::viscosity_unit::POISE;
+use crate::complex_units::{pop_complex_denominator, pop_complex_numerator, ComplexUnit};
+use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
+use crate::units::electric_current_unit::ElectricCurrentUnit;
+use crate::units::length_unit::LengthUnit;
+use crate::units::luminous_intensity_unit::LuminousIntensityUnit;
+use crate::units::mass_unit::MassUnit;
+use crate::units::temperature_unit::TemperatureDeltaUnit;
+use crate::units::time_unit::TimeUnit;
+use crate::EngUnit;
+
+/// Electrical units are shared by every preset.
+const ELECTRICAL_UNITS: [ComplexUnit; 8] = [HERTZ, COULOMB, VOLT, OHM, FARAD, HENRY, TESLA, WEBER];
+
+/// A system of units for [`EngUnit::to_system`].
+///
+/// ```
+/// use eng_units::complex_units::pressure_unit::KILOPASCAL;
+/// use eng_units::{TemperatureDeltaUnit, UnitSystem};
+///
+/// // SI, but with kPa and °C.
+/// let system = UnitSystem::Custom(
+///     UnitSystem::Si
+///         .units()
+///         .temperature(TemperatureDeltaUnit::C)
+///         .prefer(KILOPASCAL),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum UnitSystem {
+    /// kg, m, s, K with N, Pa, J and W.
+    Si,
+    /// lb, ft, s, °F with lbf, psi, BTU and hp.
+    UsCustomary,
+    /// lb, ft, s, °F with lbf, psi, BTU and hp. For now the same units as
+    /// [`UnitSystem::UsCustomary`].
+    Imperial,
+    /// g, cm, s, K with dyn, Ba, erg and P.
+    Cgs,
+    /// slug, ft, s, °R with lbf, psi, BTU and hp.
+    BritishGravitational,
+    Custom(SystemUnits),
+}
+
+/// Base units and preferred named units of a [`UnitSystem`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SystemUnits {
+    pub(crate) amount_of_substance: AmountOfSubstanceUnit,
+    pub(crate) electric_current: ElectricCurrentUnit,
+    pub(crate) length: LengthUnit,
+    pub(crate) luminous_intensity: LuminousIntensityUnit,
+    pub(crate) mass: MassUnit,
+    pub(crate) temperature: TemperatureDeltaUnit,
+    pub(crate) time: TimeUnit,
+    pub(crate) named_units: Vec<ComplexUnit>,
+}
+
+impl UnitSystem {
+    /// The units of the system, a starting point for a custom system.
+    pub fn units(&self) -> SystemUnits {
+        match self {
+            UnitSystem::Si => SystemUnits::new(
+                LengthUnit::Meter,
+                MassUnit::Kilogram,
+                TemperatureDeltaUnit::K,
+                &[NEWTON, PASCAL, JOULE, WATT],
+            ),
+            UnitSystem::UsCustomary | UnitSystem::Imperial => SystemUnits::new(
+                LengthUnit::Foot,
+                MassUnit::Pound,
+                TemperatureDeltaUnit::F,
+                &[POUND_FORCE, PSI, BTU, HORSEPOWER],
+            ),
+            UnitSystem::Cgs => SystemUnits::new(
+                LengthUnit::Centimeter,
+                MassUnit::Gram,
+                TemperatureDeltaUnit::K,
+                &[DYNE, BARYE, ERG, POISE],
+            ),
+            UnitSystem::BritishGravitational => SystemUnits::new(
+                LengthUnit::Foot,
+                MassUnit::Slug,
+                TemperatureDeltaUnit::R,
+                &[POUND_FORCE, PSI, BTU, HORSEPOWER],
+            ),
+            UnitSystem::Custom(units) => units.clone(),
+        }
+    }
+}
+
+impl SystemUnits {
+    fn new(
+        length: LengthUnit,
+        mass: MassUnit,
+        temperature: TemperatureDeltaUnit,
+        named_units: &[ComplexUnit],
+    ) -> Self {
+        let mut named_units = named_units.to_vec();
+        named_units.extend(ELECTRICAL_UNITS);
+        SystemUnits {
+            amount_of_substance: AmountOfSubstanceUnit::Mol,
+            electric_current: ElectricCurrentUnit::Ampere,
+            length,
+            luminous_intensity: LuminousIntensityUnit::Candela,
+            mass,
+            temperature,
+            time: TimeUnit::Second,
+            named_units,
+        }
+    }
+
+    pub fn length(mut self, length: LengthUnit) -> Self {
+        self.length = length;
+        self
+    }
+
+    pub fn mass(mut self, mass: MassUnit) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    pub fn temperature(mut self, temperature: TemperatureDeltaUnit) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    pub fn time(mut self, time: TimeUnit) -> Self {
+        self.time = time;
+        self
+    }
+
+    /// Prefers `complex` over the named units already in the system for its
+    /// dimension.
+    pub fn prefer(mut self, complex: ComplexUnit) -> Self {
+        self.named_units.insert(0, complex);
+        self
+    }
+
+    /// The first named unit with the same dimension as `complex`.
+    fn preferred(&self, complex: &ComplexUnit) -> Option<ComplexUnit> {
+        self.named_units
+            .iter()
+            .find(|x| x.same_dimensions(complex))
+            .copied()
+    }
+}
+
+impl EngUnit {
+    /// Expresses the unit in `system`: every named unit is replaced by the
+    /// system's preferred unit of the same dimension, or by base units if
+    /// there is none, and every base dimension is converted to the system's
+    /// base unit.
+    ///
+    /// Base units are not combined into new named units; use
+    /// [`EngUnit::simplify`] for that.
+    pub fn to_system(&self, system: &UnitSystem) -> EngUnit {
+        let units = system.units();
+        let mut unit = self.clone();
+
+        let mut unmatched = Vec::new();
+        for complex in unit.unit_numerator.iter_mut() {
+            match units.preferred(complex) {
+                Some(preferred) => {
+                    unit.value *= complex.scale_to(&preferred);
+                    *complex = preferred;
+                }
+                None => unmatched.push(*complex),
+            }
+        }
+        for complex in unmatched {
+            unit = pop_complex_numerator(&unit, complex);
+        }
+
+        let mut unmatched = Vec::new();
+        for complex in unit.unit_denominator.iter_mut() {
+            match units.preferred(complex) {
+                Some(preferred) => {
+                    unit.value /= complex.scale_to(&preferred);
+                    *complex = preferred;
+                }
+                None => unmatched.push(*complex),
+            }
+        }
+        for complex in unmatched {
+            unit = pop_complex_denominator(&unit, complex);
+        }
+
+        let mut unit = unit
+            .convert(units.amount_of_substance)
+            .convert(units.electric_current)
+            .convert(units.length)
+            .convert(units.luminous_intensity)
+            .convert(units.mass)
+            .convert(units.temperature)
+            .convert(units.time);
+        unit.clear_unused_units();
+        unit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::complex_units::energy_unit::BTU_TO_JOULES;
+    use crate::complex_units::pressure_unit::KILOPASCAL;
+    use crate::system::UnitSystem;
+    use crate::testing::assert_close;
+    use crate::units::length_unit::LengthUnit;
+    use crate::units::mass_unit::MassUnit;
+    use crate::units::temperature_unit::TemperatureDeltaUnit;
+    use crate::units::time_unit::TimeUnit;
+    use crate::{hp, kJ, kPa, kW, length, mass, temperature, time, EngUnit, MPa, N, P};
+
+    fn specific_heat() -> EngUnit {
+        kJ!(4.186) / mass!(1.0, MassUnit::Kilogram) / temperature!(1.0, TemperatureDeltaUnit::C)
+    }
+
+    #[test]
+    fn si() {
+        let unit = kW!(2.0).to_system(&UnitSystem::Si);
+        assert_eq!("2000.00 W", unit.to_string());
+        let unit = hp!(1.0).to_system(&UnitSystem::Si);
+        assert_eq!("745.70 W", unit.to_string());
+        let unit = length!(1.0, LengthUnit::Foot).to_system(&UnitSystem::Si);
+        assert_eq!("0.30 m", unit.to_string());
+    }
+
+    #[test]
+    fn us_customary() {
+        let unit = specific_heat().to_system(&UnitSystem::UsCustomary);
+        assert_eq!("BTU/lb·°F", unit.unit_to_string());
+        assert_close(4.186 / 4.1868, unit.value);
+
+        let unit = MPa!(1.0).to_system(&UnitSystem::UsCustomary);
+        assert_eq!("psi", unit.unit_to_string());
+        assert_close(145.037_737_730_209_22, unit.value);
+
+        let unit = N!(1.0).to_system(&UnitSystem::UsCustomary);
+        assert_eq!("0.22 lbf", unit.to_string());
+
+        let l = length!(1.0, LengthUnit::Meter);
+        let t = time!(1.0, TimeUnit::Second);
+        let unit = (l / t).to_system(&UnitSystem::Imperial);
+        assert_eq!("3.28 ft/s", unit.to_string());
+    }
+
+    #[test]
+    fn imperial() {
+        assert_eq!(
+            UnitSystem::UsCustomary.units(),
+            UnitSystem::Imperial.units()
+        );
+        let unit = kJ!(1.0).to_system(&UnitSystem::Imperial);
+        assert_eq!("BTU", unit.unit_to_string());
+        assert_close(1000.0 / BTU_TO_JOULES, unit.value);
+    }
+
+    #[test]
+    fn cgs() {
+        let unit = N!(1.0).to_system(&UnitSystem::Cgs);
+        assert_eq!("100000.00 dyn", unit.to_string());
+        let unit = kJ!(1.0).to_system(&UnitSystem::Cgs);
+        assert_close(1.0e10, unit.value);
+        assert_eq!("erg", unit.unit_to_string());
+        let unit = P!(1.0).to_system(&UnitSystem::Si);
+        assert_eq!("0.10 kg/m·s", unit.to_string());
+        let unit = unit.to_system(&UnitSystem::Cgs);
+        assert_eq!("1.00 g/cm·s", unit.to_string());
+    }
+
+    #[test]
+    fn british_gravitational() {
+        let unit = mass!(1.0, MassUnit::Slug).to_system(&UnitSystem::Si);
+        assert_close(14.593_902_937_206_364, unit.value);
+        let unit = unit.to_system(&UnitSystem::BritishGravitational);
+        assert_eq!("slug", unit.unit_to_string());
+        assert_close(1.0, unit.value);
+        let unit = (kJ!(1.0) / temperature!(1.0, TemperatureDeltaUnit::K))
+            .to_system(&UnitSystem::BritishGravitational);
+        assert_eq!("BTU/R", unit.unit_to_string());
+    }
+
+    #[test]
+    fn custom() {
+        let system = UnitSystem::Custom(
+            UnitSystem::Si
+                .units()
+                .temperature(TemperatureDeltaUnit::C)
+                .prefer(KILOPASCAL),
+        );
+        let unit = crate::Pa!(101_325.0).to_system(&system);
+        assert_eq!("101.33 kPa", unit.to_string());
+        let unit = kPa!(1.0) / temperature!(1.0, TemperatureDeltaUnit::F);
+        let unit = unit.to_system(&system);
+        assert_eq!("1.80 kPa/°C", unit.to_string());
+        let unit = specific_heat().to_system(&system);
+        assert_eq!("4186.00 J/kg·°C", unit.to_string());
+    }
+}
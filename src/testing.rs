@@ -0,0 +1,46 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Helpers shared by the unit tests.
+
+use crate::complex_units::{from_complex_unit, ComplexUnit};
+
+/// Relative tolerance of [`assert_close`]. Conversion factors are exact
+/// decimals or products of a few of them, so values agree to about twelve
+/// significant digits after a chain of conversions.
+pub(crate) const TOLERANCE: f64 = 1e-12;
+
+/// Asserts that `actual` is within [`TOLERANCE`] of `expected`, relative to
+/// `expected`.
+pub(crate) fn assert_close(expected: f64, actual: f64) {
+    assert!(
+        f64::abs(expected - actual) <= TOLERANCE * f64::abs(expected),
+        "expected {expected}, got {actual}"
+    );
+}
+
+/// Checks a table of named units against their value in SI base units:
+/// one of each unit must reduce to `si_value` written in `si_units`, with
+/// no named unit left.
+pub(crate) fn assert_si_values(cases: &[(ComplexUnit, f64)], si_units: &str) {
+    for (complex, si_value) in cases {
+        let x = from_complex_unit(1.0, *complex).to_si_units();
+        assert_close(*si_value, x.value);
+        assert!(x.unit_numerator.is_empty(), "{}", complex.unit_string);
+        assert!(x.unit_denominator.is_empty(), "{}", complex.unit_string);
+        assert_eq!(si_units, x.unit_to_string(), "{}", complex.unit_string);
+    }
+}
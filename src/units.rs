@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+pub mod absolute_temperature_unit;
 pub mod amount_of_substance_unit;
 pub mod electric_current_unit;
 pub mod length_unit;
@@ -22,7 +23,12 @@ pub mod mass_unit;
 pub mod temperature_unit;
 pub mod time_unit;
 
-use crate::complex_units::{pop_complex_numerator, ComplexUnit};
+use crate::complex_units::{
+    pop_complex_denominator, pop_complex_numerator, push_complex_denominator,
+    push_complex_numerator, ComplexUnit, COMPLEX_UNITS,
+};
+use crate::error::{check_finite, EngUnitError};
+use crate::format::{pad, FormatOptions, Notation, UnitOrder};
 use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
 use crate::units::electric_current_unit::ElectricCurrentUnit;
 use crate::units::length_unit::LengthUnit;
@@ -31,9 +37,7 @@ use crate::units::mass_unit::MassUnit;
 use crate::units::temperature_unit::TemperatureDeltaUnit;
 use crate::units::time_unit::TimeUnit;
 
-use std::cmp::Ordering;
-use std::collections::HashMap;
-use std::fmt::{format, Display};
+use std::fmt::{Display, LowerExp, UpperExp};
 use std::ops;
 
 #[derive(Clone, Debug)]
@@ -55,21 +59,77 @@ pub struct EngUnit {
     pub time_unit: TimeUnit,
     pub unit_numerator: Vec<ComplexUnit>,
     pub unit_denominator: Vec<ComplexUnit>,
+    /// Base dimensions in the order they were first written, used by
+    /// [`UnitOrder::AsWritten`].
+    pub(crate) dimension_order: Vec<BaseDimension>,
 }
 
+/// One of the seven SI base dimensions.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BaseDimension {
+    AmountOfSubstance,
+    ElectricCurrent,
+    Length,
+    LuminousIntensity,
+    Mass,
+    Temperature,
+    Time,
+}
+
+/// Canonical order of base units in the numerator, e.g. `kg·m^2`.
+pub const NUMERATOR_ORDER: [BaseDimension; 7] = [
+    BaseDimension::AmountOfSubstance,
+    BaseDimension::ElectricCurrent,
+    BaseDimension::Mass,
+    BaseDimension::Length,
+    BaseDimension::LuminousIntensity,
+    BaseDimension::Time,
+    BaseDimension::Temperature,
+];
+
+/// Canonical order of base units in the denominator, e.g. `/m·kg`.
+pub const DENOMINATOR_ORDER: [BaseDimension; 7] = [
+    BaseDimension::AmountOfSubstance,
+    BaseDimension::ElectricCurrent,
+    BaseDimension::Length,
+    BaseDimension::LuminousIntensity,
+    BaseDimension::Mass,
+    BaseDimension::Time,
+    BaseDimension::Temperature,
+];
+
 impl Default for EngUnit {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Honours precision (default 2), width, fill, alignment and `+`. The
+/// alternate flag `{:#}` switches to engineering notation.
 impl Display for EngUnit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.has_units() || self.has_custom_untits() {
-            write!(f, "{:.2} {}", self.value, self.unit_to_string())
+        let notation = if f.alternate() {
+            Notation::Engineering
         } else {
-            write!(f, "{:.2}", self.value)
-        }
+            Notation::Fixed
+        };
+        let options = FormatOptions::from_formatter(f, notation);
+        pad(f, &self.to_string_with(&options))
+    }
+}
+
+impl LowerExp for EngUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let options = FormatOptions::from_formatter(f, Notation::Scientific);
+        pad(f, &self.to_string_with(&options))
+    }
+}
+
+impl UpperExp for EngUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let options = FormatOptions::from_formatter(f, Notation::Scientific);
+        let value = options.format_value(self.value).replace('e', "E");
+        pad(f, &self.join_value(value))
     }
 }
 
@@ -121,11 +181,12 @@ impl EngUnit {
             amount_of_substance_unit: AmountOfSubstanceUnit::None,
             unit_numerator: Vec::new(),
             unit_denominator: Vec::new(),
+            dimension_order: Vec::new(),
         }
     }
 
     fn has_custom_untits(&self) -> bool {
-        self.unit_numerator.len() > 0 || self.unit_denominator.len() > 0
+        !self.unit_numerator.is_empty() || !self.unit_denominator.is_empty()
     }
 
     fn to_amount_unit<
@@ -241,45 +302,21 @@ impl EngUnit {
             let mut conversion_factor =
                 AmountOfSubstanceUnit::conversion_factor(from_unit, &to_unit);
             conversion_factor = f64::powf(conversion_factor, self.amount_of_substance_count as f64);
-            match self.amount_of_substance_count.cmp(&0) {
-                Ordering::Greater => {
-                    new_unit.value *= conversion_factor;
-                }
-                Ordering::Less => {
-                    new_unit.value /= conversion_factor;
-                }
-                Ordering::Equal => {}
-            }
+            new_unit.value *= conversion_factor;
             new_unit.amount_of_substance_unit = to_unit;
         } else if T::is_electric_current_unit() {
             let from_unit = &self.electric_current_unit;
             let to_unit = EngUnit::to_electric_unit(to_unit);
             let mut conversion_factor = ElectricCurrentUnit::conversion_factor(from_unit, &to_unit);
             conversion_factor = f64::powf(conversion_factor, self.electric_current_count as f64);
-            match self.electric_current_count.cmp(&0) {
-                Ordering::Greater => {
-                    new_unit.value *= conversion_factor;
-                }
-                Ordering::Less => {
-                    new_unit.value /= conversion_factor;
-                }
-                Ordering::Equal => {}
-            }
+            new_unit.value *= conversion_factor;
             new_unit.electric_current_unit = to_unit;
         } else if T::is_length_unit() {
             let from_unit = &self.length_unit;
             let to_unit = EngUnit::to_length_unit(to_unit);
             let mut conversion_factor = LengthUnit::conversion_factor(from_unit, &to_unit);
             conversion_factor = f64::powf(conversion_factor, self.length_count as f64);
-            match self.length_count.cmp(&0) {
-                Ordering::Greater => {
-                    new_unit.value *= conversion_factor;
-                }
-                Ordering::Less => {
-                    new_unit.value /= conversion_factor;
-                }
-                Ordering::Equal => {}
-            }
+            new_unit.value *= conversion_factor;
             new_unit.length_unit = to_unit;
         } else if T::is_luminous_unit() {
             let from_unit = &self.luminous_intensity_unit;
@@ -287,30 +324,14 @@ impl EngUnit {
             let mut conversion_factor =
                 LuminousIntensityUnit::conversion_factor(from_unit, &to_unit);
             conversion_factor = f64::powf(conversion_factor, self.luminous_intensity_count as f64);
-            match self.luminous_intensity_count.cmp(&0) {
-                Ordering::Greater => {
-                    new_unit.value *= conversion_factor;
-                }
-                Ordering::Less => {
-                    new_unit.value /= conversion_factor;
-                }
-                Ordering::Equal => {}
-            }
+            new_unit.value *= conversion_factor;
             new_unit.luminous_intensity_unit = to_unit;
         } else if T::is_mass_unit() {
             let from_unit = &self.mass_unit;
             let to_unit = EngUnit::to_mass_unit(to_unit);
             let mut conversion_factor = MassUnit::conversion_factor(from_unit, &to_unit);
             conversion_factor = f64::powf(conversion_factor, self.mass_count as f64);
-            match self.mass_count.cmp(&0) {
-                Ordering::Greater => {
-                    new_unit.value *= conversion_factor;
-                }
-                Ordering::Less => {
-                    new_unit.value /= conversion_factor;
-                }
-                Ordering::Equal => {}
-            }
+            new_unit.value *= conversion_factor;
             new_unit.mass_unit = to_unit;
         } else if T::is_temperature_unit() {
             let from_unit = &self.temperature_unit;
@@ -318,40 +339,142 @@ impl EngUnit {
             let mut conversion_factor =
                 TemperatureDeltaUnit::conversion_factor(from_unit, &to_unit);
             conversion_factor = f64::powf(conversion_factor, self.temperature_count as f64);
-            match self.temperature_count.cmp(&0) {
-                Ordering::Greater => {
-                    new_unit.value *= conversion_factor;
-                }
-                Ordering::Less => {
-                    new_unit.value /= conversion_factor;
-                }
-                Ordering::Equal => {}
-            }
+            new_unit.value *= conversion_factor;
             new_unit.temperature_unit = to_unit;
         } else if T::is_time_unit() {
             let from_unit = &self.time_unit;
             let to_unit = EngUnit::to_time_unit(to_unit);
-            let conversion_factor = TimeUnit::conversion_factor(from_unit, &to_unit);
-            new_unit.value *= f64::powf(conversion_factor, self.time_count as f64);
-            match self.time_count.cmp(&0) {
-                Ordering::Greater => {
-                    new_unit.value *= conversion_factor;
-                }
-                Ordering::Less => {
-                    new_unit.value /= conversion_factor;
-                }
-                Ordering::Equal => {}
-            }
+            let mut conversion_factor = TimeUnit::conversion_factor(from_unit, &to_unit);
+            conversion_factor = f64::powf(conversion_factor, self.time_count as f64);
+            new_unit.value *= conversion_factor;
             new_unit.time_unit = to_unit;
         }
         new_unit
     }
 
-    fn has_units(&self) -> bool {
-        if self.unit_numerator.len() != 0 {
+    /// Like [`EngUnit::convert`] but fails instead of silently doing nothing
+    /// when the unit has no base exponent in the target dimension.
+    pub fn try_convert<
+        T: IsEngUnitType
+            + Into<AmountOfSubstanceUnit>
+            + Into<ElectricCurrentUnit>
+            + Into<LengthUnit>
+            + Into<LuminousIntensityUnit>
+            + Into<MassUnit>
+            + Into<TemperatureDeltaUnit>
+            + Into<TimeUnit>,
+    >(
+        &self,
+        to_unit: T,
+    ) -> Result<Self, EngUnitError> {
+        let new_unit = self.convert(to_unit);
+        let si = self.to_si_units();
+        let (count, si_count, symbol) = if T::is_amount_unit() {
+            (
+                self.amount_of_substance_count,
+                si.amount_of_substance_count,
+                new_unit.amount_of_substance_unit.to_string(),
+            )
+        } else if T::is_electric_current_unit() {
+            (
+                self.electric_current_count,
+                si.electric_current_count,
+                new_unit.electric_current_unit.to_string(),
+            )
+        } else if T::is_length_unit() {
+            (
+                self.length_count,
+                si.length_count,
+                new_unit.length_unit.to_string(),
+            )
+        } else if T::is_luminous_unit() {
+            (
+                self.luminous_intensity_count,
+                si.luminous_intensity_count,
+                new_unit.luminous_intensity_unit.to_string(),
+            )
+        } else if T::is_mass_unit() {
+            (
+                self.mass_count,
+                si.mass_count,
+                new_unit.mass_unit.to_string(),
+            )
+        } else if T::is_temperature_unit() {
+            (
+                self.temperature_count,
+                si.temperature_count,
+                new_unit.temperature_unit.to_string(),
+            )
+        } else {
+            (
+                self.time_count,
+                si.time_count,
+                new_unit.time_unit.to_string(),
+            )
+        };
+
+        if count == 0 {
+            if si_count != 0 {
+                // The dimension only exists inside a complex unit such as kJ.
+                let complex = self
+                    .unit_numerator
+                    .iter()
+                    .chain(self.unit_denominator.iter())
+                    .map(|c| c.unit_to_string())
+                    .collect::<Vec<String>>()
+                    .join("·");
+                return Err(EngUnitError::IncompatibleComplexUnit {
+                    unit: symbol.to_string(),
+                    complex,
+                });
+            }
+            return Err(EngUnitError::DimensionMismatch {
+                left: self.unit_to_string(),
+                right: symbol.to_string(),
+            });
+        }
+        check_finite(new_unit.value)?;
+        Ok(new_unit)
+    }
+
+    /// Formats the unit with explicit [`FormatOptions`] instead of the
+    /// flags of a formatter.
+    pub fn to_string_with(&self, options: &FormatOptions) -> String {
+        let (unit, value) = self.formatted_parts(options);
+        unit.join_value_with(value, options)
+    }
+
+    /// The unit after any automatic prefix, and its formatted value.
+    pub(crate) fn formatted_parts(&self, options: &FormatOptions) -> (EngUnit, String) {
+        let unit = match &options.prefixes {
+            Some(prefixes) => self.with_auto_prefix(prefixes),
+            None => self.clone(),
+        };
+        let value = options.format_value(unit.value);
+        (unit, value)
+    }
+
+    fn join_value(&self, value: String) -> String {
+        self.join_value_with(value, &FormatOptions::new())
+    }
+
+    fn join_value_with(&self, value: String, options: &FormatOptions) -> String {
+        if self.has_units() || self.has_custom_untits() {
+            format!(
+                "{} {}",
+                value,
+                self.render_unit(options.ascii, options.unit_order)
+            )
+        } else {
+            value
+        }
+    }
+
+    pub(crate) fn has_units(&self) -> bool {
+        if !self.unit_numerator.is_empty() {
             return true;
         }
-        if self.unit_denominator.len() != 0 {
+        if !self.unit_denominator.is_empty() {
             return true;
         }
         if self.length_count != 0 {
@@ -378,284 +501,139 @@ impl EngUnit {
         false
     }
 
+    /// Unit symbols in canonical order, see [`UnitOrder::Canonical`].
     pub fn unit_to_string(&self) -> String {
-        let mut s_numerator: Vec<String> = Vec::new();
-        let mut s_denominator: Vec<String> = Vec::new();
-
-        if !self.unit_numerator.is_empty() {
-            let mut map: HashMap<&str, i32> = HashMap::new();
-            for u in &self.unit_numerator {
-                match map.get(u.unit_string) {
-                    None => map.insert(u.unit_string, 1),
-                    Some(val) => map.insert(u.unit_string, val + 1),
-                };
-            }
-            for (key, val) in map {
-                if val > 1 {
-                    let s = format!("{key}^{val}");
-                    s_numerator.push(s)
-                } else {
-                    let s = format!("{key}");
-                    s_numerator.push(s)
-                }
-            }
-        }
-
-        if self.amount_of_substance_count >= 2 {
-            let s = format!(
-                "{}^{}",
-                self.amount_of_substance_unit.to_string(),
-                self.amount_of_substance_count
-            );
-            s_numerator.push(s);
-        } else if self.amount_of_substance_count == 1 {
-            let s = self.amount_of_substance_unit.to_string();
-            s_numerator.push(s.to_string());
-        }
-        if self.electric_current_count >= 2 {
-            let s = format!(
-                "{}^{}",
-                self.electric_current_unit.to_string(),
-                self.electric_current_count
-            );
-            s_numerator.push(s);
-        } else if self.electric_current_count == 1 {
-            let s = self.electric_current_unit.to_string();
-            s_numerator.push(s.to_string());
-        }
-        if self.mass_count >= 2 {
-            let s = format!("{}^{}", self.mass_unit.to_string(), self.mass_count);
-            s_numerator.push(s);
-        } else if self.mass_count == 1 {
-            let s = self.mass_unit.to_string();
-            s_numerator.push(s.to_string());
-        }
-        if self.length_count >= 2 {
-            let s = format!("{}^{}", self.length_unit.to_string(), self.length_count);
-            s_numerator.push(s);
-        } else if self.length_count == 1 {
-            let s = self.length_unit.to_string();
-            s_numerator.push(s.to_string());
-        }
-        if self.luminous_intensity_count >= 2 {
-            let s = format!(
-                "{}^{}",
-                self.luminous_intensity_unit.to_string(),
-                self.luminous_intensity_count
-            );
-            s_numerator.push(s);
-        } else if self.luminous_intensity_count == 1 {
-            let s = self.luminous_intensity_unit.to_string();
-            s_numerator.push(s.to_string());
-        }
-        if self.time_count >= 2 {
-            let s = format!("{}^{}", self.time_unit.to_string(), self.time_count);
-            s_numerator.push(s);
-        } else if self.time_count == 1 {
-            let s = self.time_unit.to_string();
-            s_numerator.push(s.to_string());
-        }
-        if self.temperature_count >= 2 {
-            let s = format!(
-                "{}^{}",
-                self.temperature_unit.to_string(),
-                self.temperature_count
-            );
-            s_numerator.push(s);
-        } else if self.temperature_count == 1 {
-            let s = self.temperature_unit.to_string();
-            s_numerator.push(s.to_string());
-        }
-
-        // String Denominator
-        for u in &self.unit_denominator {
-            s_denominator.push(u.unit_to_string())
-        }
-        if self.amount_of_substance_count <= -2 {
-            let s = format!(
-                "{}^{}",
-                self.amount_of_substance_unit.to_string(),
-                self.amount_of_substance_count
-            );
-            s_denominator.push(s);
-        } else if self.amount_of_substance_count == -1 {
-            let s = self.amount_of_substance_unit.to_string();
-            s_denominator.push(s.to_string());
-        }
-
-        if self.electric_current_count <= -2 {
-            let s = format!(
-                "{}^{}",
-                self.electric_current_unit.to_string(),
-                self.electric_current_count
-            );
-            s_denominator.push(s);
-        } else if self.electric_current_count == -1 {
-            let s = self.electric_current_unit.to_string();
-            s_denominator.push(s.to_string());
-        }
-
-        if self.length_count <= -2 {
-            let s = format!("{}^{}", self.length_unit.to_string(), self.length_count);
-            s_denominator.push(s);
-        } else if self.length_count == -1 {
-            let s = self.length_unit.to_string();
-            s_denominator.push(s.to_string());
-        }
-
-        if self.luminous_intensity_count <= -2 {
-            let s = format!(
-                "{}^{}",
-                self.luminous_intensity_unit.to_string(),
-                self.luminous_intensity_count
-            );
-            s_denominator.push(s);
-        } else if self.luminous_intensity_count == -1 {
-            let s = self.luminous_intensity_unit.to_string();
-            s_denominator.push(s.to_string());
-        }
-
-        if self.mass_count <= -2 {
-            let s = format!("{}^{}", self.mass_unit.to_string(), self.mass_count);
-            s_denominator.push(s);
-        } else if self.mass_count == -1 {
-            let s = self.mass_unit.to_string();
-            s_denominator.push(s.to_string());
-        }
-
-        if self.time_count <= -2 {
-            let s = format!(
-                "{}^{}",
-                self.time_unit.to_string(),
-                i32::abs(self.time_count)
-            );
-            s_denominator.push(s);
-        } else if self.time_count == -1 {
-            let s = self.time_unit.to_string();
-            s_denominator.push(s.to_string());
-        }
+        self.render_unit(false, UnitOrder::Canonical)
+    }
 
-        if self.temperature_count <= -2 {
-            let s = format!(
-                "{}^{}",
-                self.temperature_unit.to_string(),
-                self.temperature_count
-            );
-            s_denominator.push(s);
-        } else if self.temperature_count == -1 {
-            let s = self.temperature_unit.to_string();
-            s_denominator.push(s.to_string());
-        }
+    /// Like [`EngUnit::unit_to_string`] but restricted to ASCII: `*` between
+    /// factors, `degC`/`degF`, `u` for micro and `ohm`, e.g. `kg*m^2/s^2`.
+    pub fn unit_to_ascii_string(&self) -> String {
+        self.render_unit(true, UnitOrder::Canonical)
+    }
 
-        let mut s_output = String::new();
-        for s in s_numerator.iter() {
-            s_output.push_str(s);
-            s_output.push('·');
-        }
-        if !s_numerator.is_empty() {
-            let mut chars = s_output.chars();
-            chars.next_back();
-            s_output = chars.as_str().to_string();
-        }
+    /// Value and unit in the ASCII form of [`EngUnit::unit_to_ascii_string`].
+    pub fn to_ascii_string(&self) -> String {
+        self.to_string_with(&FormatOptions::new().ascii(true))
+    }
 
-        if !s_denominator.is_empty() {
+    fn render_unit(&self, ascii: bool, order: UnitOrder) -> String {
+        let (numerator, denominator) = self.unit_terms(order);
+        let separator = if ascii { "*" } else { "·" };
+        let join = |terms: &[UnitTerm]| {
+            terms
+                .iter()
+                .map(|term| {
+                    let symbol = if ascii {
+                        ascii_symbol(term.symbol)
+                    } else {
+                        term.symbol.to_string()
+                    };
+                    if term.exponent > 1 {
+                        format!("{}^{}", symbol, term.exponent)
+                    } else {
+                        symbol
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(separator)
+        };
+        let mut s_output = join(&numerator);
+        if !denominator.is_empty() {
             s_output.push('/');
-        }
-        for s in s_denominator.iter() {
-            s_output.push_str(s);
-            s_output.push('·');
-        }
-        if !s_denominator.is_empty() {
-            let mut chars = s_output.chars();
-            chars.next_back();
-            s_output = chars.as_str().to_string();
+            s_output.push_str(&join(&denominator));
         }
         s_output
     }
 
-    pub fn to_latex(&self) -> String {
-        if !self.has_units() {
-            return format!("${}$", self.value);
-        }
-
-        let mut s_numerator: Vec<String> = Vec::new();
-        let mut s_denominator: Vec<String> = Vec::new();
-
-        // String Numerator
-        if self.mass_count >= 2 {
-            let s = format!("{}^{}", self.mass_unit.to_string(), self.mass_count);
-            s_numerator.push(s);
-        } else if self.mass_count == 1 {
-            let s = self.mass_unit.to_string();
-            s_numerator.push(s.to_string());
-        }
-
-        if self.temperature_count >= 2 {
-            let s = format!(
-                "{}^{}",
-                self.temperature_unit.to_string(),
-                self.temperature_count
-            );
-            s_numerator.push(s);
-        } else if self.temperature_count == 1 {
-            let s = self.temperature_unit.to_latex();
-            s_numerator.push(s.to_string());
-        }
-
-        if self.time_count >= 2 {
-            let s = format!("{}^{}", self.time_unit.to_string(), self.time_count);
-            s_numerator.push(s);
-        } else if self.time_count == 1 {
-            let s = self.time_unit.to_string();
-            s_numerator.push(s.to_string());
-        }
-
-        // String Denominator
-        if self.mass_count <= -2 {
-            let s = format!("{}^{}", self.mass_unit.to_string(), self.mass_count);
-            s_denominator.push(s);
-        } else if self.mass_count == -1 {
-            let s = self.mass_unit.to_string();
-            s_denominator.push(s.to_string());
+    /// Splits the unit into numerator and denominator terms with positive
+    /// exponents, in display order. Shared by every text renderer.
+    ///
+    /// In [`UnitOrder::Canonical`] named units come first in the order of
+    /// [`COMPLEX_UNITS`], followed by base units in [`NUMERATOR_ORDER`] or
+    /// [`DENOMINATOR_ORDER`]. [`UnitOrder::AsWritten`] keeps named units and
+    /// base units in the order they were written.
+    pub(crate) fn unit_terms(&self, order: UnitOrder) -> (Vec<UnitTerm>, Vec<UnitTerm>) {
+        let mut numerator_units = self.unit_numerator.clone();
+        let mut denominator_units = self.unit_denominator.clone();
+        let (numerator_order, denominator_order) = match order {
+            UnitOrder::Canonical => {
+                numerator_units.sort_by_key(registry_position);
+                denominator_units.sort_by_key(registry_position);
+                (NUMERATOR_ORDER.to_vec(), DENOMINATOR_ORDER.to_vec())
+            }
+            UnitOrder::AsWritten => (self.written_dimensions(), self.written_dimensions()),
+        };
+
+        let mut numerator = group_complex_units(&numerator_units);
+        for dimension in numerator_order {
+            let (count, symbol) = self.dimension_term(dimension);
+            if count > 0 {
+                numerator.push(UnitTerm {
+                    symbol,
+                    exponent: count,
+                });
+            }
         }
 
-        if self.temperature_count <= -2 {
-            let s = format!(
-                "{}^{}",
-                self.temperature_unit.to_string(),
-                self.temperature_count
-            );
-            s_denominator.push(s);
-        } else if self.temperature_count == -1 {
-            let s = self.temperature_unit.to_string();
-            s_denominator.push(s.to_string());
+        let mut denominator = group_complex_units(&denominator_units);
+        for dimension in denominator_order {
+            let (count, symbol) = self.dimension_term(dimension);
+            if count < 0 {
+                denominator.push(UnitTerm {
+                    symbol,
+                    exponent: i32::abs(count),
+                });
+            }
         }
+        (numerator, denominator)
+    }
 
-        if self.time_count <= -2 {
-            let s = format!(
-                "{}^{}",
-                self.time_unit.to_string(),
-                i32::abs(self.time_count)
-            );
-            s_denominator.push(s);
-        } else if self.time_count == -1 {
-            let s = self.time_unit.to_string();
-            s_denominator.push(s.to_string());
+    fn dimension_term(&self, dimension: BaseDimension) -> (i32, &'static str) {
+        match dimension {
+            BaseDimension::AmountOfSubstance => (
+                self.amount_of_substance_count,
+                self.amount_of_substance_unit.to_string(),
+            ),
+            BaseDimension::ElectricCurrent => (
+                self.electric_current_count,
+                self.electric_current_unit.to_string(),
+            ),
+            BaseDimension::Length => (self.length_count, self.length_unit.to_string()),
+            BaseDimension::LuminousIntensity => (
+                self.luminous_intensity_count,
+                self.luminous_intensity_unit.to_string(),
+            ),
+            BaseDimension::Mass => (self.mass_count, self.mass_unit.to_string()),
+            BaseDimension::Temperature => {
+                (self.temperature_count, self.temperature_unit.to_string())
+            }
+            BaseDimension::Time => (self.time_count, self.time_unit.to_string()),
         }
+    }
 
-        let mut s_output = format!("${}\\ ", self.value);
-        for s in s_numerator.iter() {
-            s_output.push_str(s);
+    /// Records that `dimension` was written, if it is not already known.
+    pub(crate) fn record_dimension(&mut self, dimension: BaseDimension) {
+        if !self.dimension_order.contains(&dimension) {
+            self.dimension_order.push(dimension);
         }
+    }
 
-        if !s_denominator.is_empty() {
-            s_output.push('/');
-        }
-        for s in s_denominator.iter() {
-            s_output.push_str(s);
+    /// The base dimensions in use: those in [`EngUnit::dimension_order`]
+    /// first, then any others in [`NUMERATOR_ORDER`].
+    fn written_dimensions(&self) -> Vec<BaseDimension> {
+        let mut dimensions: Vec<BaseDimension> = self
+            .dimension_order
+            .iter()
+            .copied()
+            .filter(|dimension| self.dimension_term(*dimension).0 != 0)
+            .collect();
+        for dimension in NUMERATOR_ORDER {
+            if self.dimension_term(dimension).0 != 0 && !dimensions.contains(&dimension) {
+                dimensions.push(dimension);
+            }
         }
-        s_output.push('$');
-        s_output
+        dimensions
     }
 
     fn multiply_units(self, other: &EngUnit) -> EngUnit {
@@ -670,56 +648,53 @@ impl EngUnit {
         for complex in &other.unit_numerator {
             new_unit.unit_numerator.push(*complex);
         }
+        for complex in &other.unit_denominator {
+            new_unit.unit_denominator.push(*complex);
+        }
+        new_unit.dimension_order = self.written_dimensions();
+        for dimension in other.written_dimensions() {
+            new_unit.record_dimension(dimension);
+        }
 
         let mut amount_conversion_factor = AmountOfSubstanceUnit::conversion_factor(
             &other.amount_of_substance_unit,
             &self.amount_of_substance_unit,
         );
-        if other.amount_of_substance_count < 0 {
-            amount_conversion_factor = 1.0 / amount_conversion_factor;
-        }
+        amount_conversion_factor =
+            f64::powi(amount_conversion_factor, other.amount_of_substance_count);
 
         let mut electric_conversion_factor = ElectricCurrentUnit::conversion_factor(
             &other.electric_current_unit,
             &self.electric_current_unit,
         );
-        if other.electric_current_count < 0 {
-            electric_conversion_factor = 1.0 / electric_conversion_factor;
-        }
+        electric_conversion_factor =
+            f64::powi(electric_conversion_factor, other.electric_current_count);
 
         let mut length_conversion_factor =
             LengthUnit::conversion_factor(&other.length_unit, &self.length_unit);
-        if other.length_count < 0 {
-            length_conversion_factor = 1.0 / length_conversion_factor;
-        }
+        length_conversion_factor = f64::powi(length_conversion_factor, other.length_count);
 
         let mut luminous_conversion_factor = LuminousIntensityUnit::conversion_factor(
             &other.luminous_intensity_unit,
             &self.luminous_intensity_unit,
         );
-        if other.luminous_intensity_count < 0 {
-            luminous_conversion_factor = 1.0 / luminous_conversion_factor;
-        }
+        luminous_conversion_factor =
+            f64::powi(luminous_conversion_factor, other.luminous_intensity_count);
 
         let mut mass_conversion_factor =
             MassUnit::conversion_factor(&other.mass_unit, &self.mass_unit);
-        if other.mass_count < 0 {
-            mass_conversion_factor = 1.0 / mass_conversion_factor;
-        }
+        mass_conversion_factor = f64::powi(mass_conversion_factor, other.mass_count);
 
         let mut temperature_conversion_factor = TemperatureDeltaUnit::conversion_factor(
             &other.temperature_unit,
             &self.temperature_unit,
         );
-        if other.temperature_count < 0 {
-            temperature_conversion_factor = 1.0 / temperature_conversion_factor;
-        }
+        temperature_conversion_factor =
+            f64::powi(temperature_conversion_factor, other.temperature_count);
 
         let mut time_conversion_factor =
             TimeUnit::conversion_factor(&other.time_unit, &self.time_unit);
-        if other.time_count < 0 {
-            time_conversion_factor = 1.0 / time_conversion_factor;
-        }
+        time_conversion_factor = f64::powi(time_conversion_factor, other.time_count);
 
         new_unit.value = self.value * other.value;
         new_unit.value *= amount_conversion_factor;
@@ -729,6 +704,7 @@ impl EngUnit {
         new_unit.value *= mass_conversion_factor;
         new_unit.value *= temperature_conversion_factor;
         new_unit.value *= time_conversion_factor;
+        new_unit.cancel_complex_units();
 
         if new_unit.amount_of_substance_count != 0 {
             if self.amount_of_substance_count != 0 {
@@ -817,14 +793,148 @@ impl EngUnit {
         recip.mass_count *= -1;
         recip.temperature_count *= -1;
         recip.time_count *= -1;
+        std::mem::swap(&mut recip.unit_numerator, &mut recip.unit_denominator);
         recip
     }
 
+    /// Removes named units that appear in both the numerator and the
+    /// denominator. Named units of the same dimension cancel as well, with
+    /// their ratio folded into the value: `kW/W` becomes `1000`.
+    fn cancel_complex_units(&mut self) {
+        self.cancel_complex_units_where(|a, b| a == b);
+        let mut scale = 1.0;
+        self.cancel_complex_units_where(|a, b| {
+            let same = a.same_dimensions(b);
+            if same {
+                scale *= a.scale_to(b);
+            }
+            same
+        });
+        self.value *= scale;
+    }
+
+    fn cancel_complex_units_where(
+        &mut self,
+        mut cancels: impl FnMut(&ComplexUnit, &ComplexUnit) -> bool,
+    ) {
+        let mut index = 0;
+        while index < self.unit_numerator.len() {
+            let complex = self.unit_numerator[index];
+            match self
+                .unit_denominator
+                .iter()
+                .position(|x| cancels(&complex, x))
+            {
+                Some(position) => {
+                    self.unit_denominator.remove(position);
+                    self.unit_numerator.remove(index);
+                }
+                None => index += 1,
+            }
+        }
+    }
+
+    /// Replaces base-unit exponents with named units from
+    /// [`COMPLEX_UNITS`], e.g. `kg·m/s^2` becomes `N`.
+    ///
+    /// Only coherent catalog units (no prefix) are considered. Each step
+    /// takes the named unit, in the numerator or the denominator, that
+    /// leaves the fewest exponents behind; a named unit counts as one
+    /// exponent so that `m/s` is not rewritten as `m·Hz`. Named units
+    /// already on `self` are kept.
+    pub fn simplify(&self) -> EngUnit {
+        let candidates: Vec<ComplexUnit> = COMPLEX_UNITS
+            .iter()
+            .filter(|c| c.prefix_multiplier == 1.0)
+            .copied()
+            .collect();
+        self.simplify_with(&candidates, false)
+    }
+
+    /// [`EngUnit::simplify`] with an explicit list of named units to try.
+    /// Earlier units win ties. With `keep_value`, a named unit is only used
+    /// if it renames the exponents it replaces without rescaling the value.
+    pub(crate) fn simplify_with(&self, candidates: &[ComplexUnit], keep_value: bool) -> EngUnit {
+        let mut unit = self.clone();
+        loop {
+            let mut best: Option<EngUnit> = None;
+            let mut best_score = unit.simplify_score();
+            for complex in candidates {
+                let candidates = [
+                    push_complex_numerator(&unit, *complex),
+                    push_complex_denominator(&unit, *complex),
+                ];
+                for candidate in candidates.into_iter().flatten() {
+                    if keep_value
+                        && f64::abs(candidate.value - unit.value) > 1e-9 * f64::abs(unit.value)
+                    {
+                        continue;
+                    }
+                    let score = candidate.simplify_score();
+                    if score < best_score {
+                        best_score = score;
+                        best = Some(candidate);
+                    }
+                }
+            }
+            match best {
+                Some(candidate) => unit = candidate,
+                None => break,
+            }
+        }
+        unit.clear_unused_units();
+        unit
+    }
+
+    /// Display string of [`EngUnit::simplify`].
+    pub fn to_simplified_string(&self) -> String {
+        self.simplify().to_string()
+    }
+
+    /// Resets the unit of every dimension whose exponent is zero.
+    pub(crate) fn clear_unused_units(&mut self) {
+        if self.amount_of_substance_count == 0 {
+            self.amount_of_substance_unit = AmountOfSubstanceUnit::None;
+        }
+        if self.electric_current_count == 0 {
+            self.electric_current_unit = ElectricCurrentUnit::None;
+        }
+        if self.length_count == 0 {
+            self.length_unit = LengthUnit::None;
+        }
+        if self.luminous_intensity_count == 0 {
+            self.luminous_intensity_unit = LuminousIntensityUnit::None;
+        }
+        if self.mass_count == 0 {
+            self.mass_unit = MassUnit::None;
+        }
+        if self.temperature_count == 0 {
+            self.temperature_unit = TemperatureDeltaUnit::None;
+        }
+        if self.time_count == 0 {
+            self.time_unit = TimeUnit::None;
+        }
+    }
+
+    fn simplify_score(&self) -> i32 {
+        self.amount_of_substance_count.abs()
+            + self.electric_current_count.abs()
+            + self.length_count.abs()
+            + self.luminous_intensity_count.abs()
+            + self.mass_count.abs()
+            + self.temperature_count.abs()
+            + self.time_count.abs()
+            + (self.unit_numerator.len() + self.unit_denominator.len()) as i32
+    }
+
     pub fn to_si_units(&self) -> EngUnit {
         let mut new_unit = self.clone();
         for complex in &self.unit_numerator {
             new_unit = pop_complex_numerator(&new_unit, *complex);
         }
+        for complex in &self.unit_denominator {
+            new_unit = pop_complex_denominator(&new_unit, *complex);
+        }
         new_unit = new_unit.convert(TimeUnit::Second);
         new_unit = new_unit.convert(LengthUnit::Meter);
         new_unit = new_unit.convert(MassUnit::Kilogram);
@@ -834,6 +944,58 @@ impl EngUnit {
         new_unit = new_unit.convert(LuminousIntensityUnit::Candela);
         new_unit
     }
+
+    /// Like [`EngUnit::to_si_units`] but fails if the value is, or becomes,
+    /// `NaN` or infinite.
+    pub fn try_to_si_units(&self) -> Result<EngUnit, EngUnitError> {
+        check_finite(self.value)?;
+        let new_unit = self.to_si_units();
+        check_finite(new_unit.value)?;
+        Ok(new_unit)
+    }
+
+    /// Adds `other` after converting it into the units of `self`.
+    ///
+    /// Returns [`EngUnitError::DimensionMismatch`] instead of panicking when
+    /// the two units do not share the same dimension.
+    pub fn checked_add(&self, other: &EngUnit) -> Result<EngUnit, EngUnitError> {
+        let other_value = other.value_in_units_of(self)?;
+        let mut new_unit = self.clone();
+        new_unit.value += other_value;
+        Ok(new_unit)
+    }
+
+    /// Subtracts `other` after converting it into the units of `self`.
+    ///
+    /// Returns [`EngUnitError::DimensionMismatch`] instead of panicking when
+    /// the two units do not share the same dimension.
+    pub fn checked_sub(&self, other: &EngUnit) -> Result<EngUnit, EngUnitError> {
+        let other_value = other.value_in_units_of(self)?;
+        let mut new_unit = self.clone();
+        new_unit.value -= other_value;
+        Ok(new_unit)
+    }
+
+    /// Expresses the unit in the units of `target`, e.g. `kW·hr` in `MJ`.
+    /// The value of `target` is ignored.
+    pub fn convert_to(&self, target: &EngUnit) -> Result<EngUnit, EngUnitError> {
+        let mut new_unit = target.clone();
+        new_unit.value = self.value_in_units_of(target)?;
+        check_finite(new_unit.value)?;
+        Ok(new_unit)
+    }
+
+    fn value_in_units_of(&self, target: &EngUnit) -> Result<f64, EngUnitError> {
+        if !same_dimensions(self, target) {
+            return Err(EngUnitError::DimensionMismatch {
+                left: target.unit_to_string(),
+                right: self.unit_to_string(),
+            });
+        }
+        let mut target_unit = target.clone();
+        target_unit.value = 1.0;
+        Ok(self.to_si_units().value / target_unit.to_si_units().value)
+    }
 }
 
 impl ops::Mul for EngUnit {
@@ -902,6 +1064,174 @@ impl ops::Mul<&EngUnit> for f64 {
     }
 }
 
+impl ops::Div<f64> for EngUnit {
+    type Output = EngUnit;
+    fn div(self, rhs: f64) -> Self::Output {
+        let mut new_unit = self.clone();
+        new_unit.value /= rhs;
+        new_unit
+    }
+}
+
+impl ops::Div<f64> for &EngUnit {
+    type Output = EngUnit;
+    fn div(self, rhs: f64) -> Self::Output {
+        let mut new_unit = self.clone();
+        new_unit.value /= rhs;
+        new_unit
+    }
+}
+
+impl ops::Add for EngUnit {
+    type Output = EngUnit;
+    fn add(self, rhs: Self) -> Self::Output {
+        match self.checked_add(&rhs) {
+            Ok(new_unit) => new_unit,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl ops::Add for &EngUnit {
+    type Output = EngUnit;
+    fn add(self, rhs: Self) -> Self::Output {
+        match self.checked_add(rhs) {
+            Ok(new_unit) => new_unit,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl ops::Sub for EngUnit {
+    type Output = EngUnit;
+    fn sub(self, rhs: Self) -> Self::Output {
+        match self.checked_sub(&rhs) {
+            Ok(new_unit) => new_unit,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl ops::Sub for &EngUnit {
+    type Output = EngUnit;
+    fn sub(self, rhs: Self) -> Self::Output {
+        match self.checked_sub(rhs) {
+            Ok(new_unit) => new_unit,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl ops::Neg for EngUnit {
+    type Output = EngUnit;
+    fn neg(self) -> Self::Output {
+        let mut new_unit = self.clone();
+        new_unit.value = -new_unit.value;
+        new_unit
+    }
+}
+
+impl ops::Neg for &EngUnit {
+    type Output = EngUnit;
+    fn neg(self) -> Self::Output {
+        let mut new_unit = self.clone();
+        new_unit.value = -new_unit.value;
+        new_unit
+    }
+}
+
+impl ops::AddAssign for EngUnit {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = &*self + &rhs;
+    }
+}
+
+impl ops::SubAssign for EngUnit {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = &*self - &rhs;
+    }
+}
+
+impl ops::MulAssign for EngUnit {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl ops::MulAssign<f64> for EngUnit {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.value *= rhs;
+    }
+}
+
+impl ops::DivAssign for EngUnit {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = &*self / &rhs;
+    }
+}
+
+impl ops::DivAssign<f64> for EngUnit {
+    fn div_assign(&mut self, rhs: f64) {
+        self.value /= rhs;
+    }
+}
+
+/// One symbol of a unit string with its exponent, always positive.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct UnitTerm {
+    pub(crate) symbol: &'static str,
+    pub(crate) exponent: i32,
+}
+
+/// ASCII spelling of a unit symbol: `°C` is `degC`, `µm` is `um` and
+/// `kΩ` is `kohm`.
+pub(crate) fn ascii_symbol(symbol: &str) -> String {
+    symbol
+        .replace('°', "deg")
+        .replace('µ', "u")
+        .replace('Ω', "ohm")
+        .replace('·', "*")
+}
+
+/// Index of `complex` in [`COMPLEX_UNITS`]; units outside the catalog sort
+/// last.
+fn registry_position(complex: &ComplexUnit) -> usize {
+    COMPLEX_UNITS
+        .iter()
+        .position(|x| x == complex)
+        .unwrap_or(COMPLEX_UNITS.len())
+}
+
+/// Collapses repeated named units into a single term, keeping the order
+/// in which they first appear.
+fn group_complex_units(units: &[ComplexUnit]) -> Vec<UnitTerm> {
+    let mut terms: Vec<UnitTerm> = Vec::new();
+    for u in units {
+        match terms.iter_mut().find(|term| term.symbol == u.unit_string) {
+            Some(term) => term.exponent += 1,
+            None => terms.push(UnitTerm {
+                symbol: u.unit_string,
+                exponent: 1,
+            }),
+        }
+    }
+    terms
+}
+
+/// Returns `true` if both units reduce to the same SI base dimensions,
+/// regardless of the units they are expressed in.
+pub fn same_dimensions(unit_1: &EngUnit, unit_2: &EngUnit) -> bool {
+    let unit_1 = unit_1.to_si_units();
+    let unit_2 = unit_2.to_si_units();
+    unit_1.amount_of_substance_count == unit_2.amount_of_substance_count
+        && unit_1.electric_current_count == unit_2.electric_current_count
+        && unit_1.length_count == unit_2.length_count
+        && unit_1.luminous_intensity_count == unit_2.luminous_intensity_count
+        && unit_1.mass_count == unit_2.mass_count
+        && unit_1.temperature_count == unit_2.temperature_count
+        && unit_1.time_count == unit_2.time_count
+}
+
 pub fn same_units(unit_1: &EngUnit, unit_2: &EngUnit) -> bool {
     if unit_1.amount_of_substance_unit != unit_2.amount_of_substance_unit {
         return false;
@@ -951,8 +1281,11 @@ pub fn same_units(unit_1: &EngUnit, unit_2: &EngUnit) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use crate::temperature;
+    use crate::error::EngUnitError;
+    use crate::format::{FormatOptions, Notation, UnitOrder};
+    use crate::units::same_dimensions;
     use crate::*;
+    use crate::{kJ, length, mass, temperature, time};
 
     #[test]
     fn new_eng_unt() {
@@ -964,7 +1297,7 @@ mod tests {
         assert_eq!(0, new_unit.electric_current_count);
         assert_eq!(0, new_unit.luminous_intensity_count);
         assert_eq!(0, new_unit.amount_of_substance_count);
-        assert_eq!(false, new_unit.has_units());
+        assert!(!new_unit.has_units());
     }
 
     #[test]
@@ -987,7 +1320,7 @@ mod tests {
         assert_eq!(1, unit3.electric_current_count);
         assert_eq!(1, unit3.luminous_intensity_count);
         assert_eq!(1, unit3.amount_of_substance_count);
-        assert_eq!(true, unit3.has_units());
+        assert!(unit3.has_units());
     }
 
     #[test]
@@ -1010,7 +1343,7 @@ mod tests {
         assert_eq!(1, unit3.electric_current_count);
         assert_eq!(1, unit3.luminous_intensity_count);
         assert_eq!(1, unit3.amount_of_substance_count);
-        assert_eq!(true, unit3.has_units());
+        assert!(unit3.has_units());
     }
 
     #[test]
@@ -1033,7 +1366,7 @@ mod tests {
         assert_eq!(-1, unit3.electric_current_count);
         assert_eq!(-1, unit3.luminous_intensity_count);
         assert_eq!(-1, unit3.amount_of_substance_count);
-        assert_eq!(true, unit3.has_units());
+        assert!(unit3.has_units());
     }
 
     #[test]
@@ -1056,7 +1389,7 @@ mod tests {
         assert_eq!(-1, unit3.electric_current_count);
         assert_eq!(-1, unit3.luminous_intensity_count);
         assert_eq!(-1, unit3.amount_of_substance_count);
-        assert_eq!(true, unit3.has_units());
+        assert!(unit3.has_units());
     }
 
     #[test]
@@ -1128,7 +1461,7 @@ mod tests {
         assert_eq!(100.0, unit_3.value);
         assert_eq!(1, unit_3.temperature_count);
         assert_eq!(TemperatureDeltaUnit::C, unit_3.temperature_unit);
-        assert_eq!(true, unit_3.has_units())
+        assert!(unit_3.has_units())
     }
 
     #[test]
@@ -1143,6 +1476,168 @@ mod tests {
         assert!(!unit_3.has_units())
     }
 
+    #[test]
+    fn convert_negative_exponent() {
+        let mut unit = EngUnit::new();
+        unit.length_count = -1;
+        unit.length_unit = LengthUnit::Meter;
+        let unit = unit.convert(LengthUnit::Foot);
+        assert!(f64::abs(0.3048 - unit.value) < 1e-12);
+        assert_eq!("0.30 /ft", unit.to_string());
+    }
+
+    #[test]
+    fn convert_time() {
+        let unit = time!(2.0, TimeUnit::Minute);
+        let unit = unit.convert(TimeUnit::Second);
+        assert_eq!(120.0, unit.value);
+    }
+
+    #[test]
+    fn add_same_units() {
+        let a = mass!(1.0, MassUnit::Kilogram);
+        let b = mass!(2.5, MassUnit::Kilogram);
+        let c = a + b;
+        assert_eq!(3.5, c.value);
+        assert_eq!("3.50 kg", c.to_string());
+    }
+
+    #[test]
+    fn add_converts_rhs() {
+        let a = mass!(1.0, MassUnit::Kilogram);
+        let b = mass!(1.0, MassUnit::Pound);
+        let c = &a + &b;
+        assert!(f64::abs(1.0 + 0.453_592_37 - c.value) < 1e-12);
+        assert_eq!(MassUnit::Kilogram, c.mass_unit);
+
+        let d = b + a;
+        assert!(f64::abs(1.0 + 1.0 / 0.453_592_37 - d.value) < 1e-12);
+        assert_eq!(MassUnit::Pound, d.mass_unit);
+    }
+
+    #[test]
+    fn add_compound_units() {
+        let a = mass!(1.0, MassUnit::Kilogram) / time!(1.0, TimeUnit::Second);
+        let b = mass!(60.0, MassUnit::Kilogram) / time!(1.0, TimeUnit::Minute);
+        let c = a + b;
+        assert!(f64::abs(2.0 - c.value) < 1e-9);
+        assert_eq!("2.00 kg/s", c.to_string());
+    }
+
+    #[test]
+    fn sub_converts_rhs() {
+        let a = temperature!(10.0, TemperatureDeltaUnit::C);
+        let b = temperature!(9.0, TemperatureDeltaUnit::F);
+        let c = a - b;
+        assert!(f64::abs(5.0 - c.value) < 1e-9);
+        assert_eq!("5.00 °C", c.to_string());
+    }
+
+    #[test]
+    fn checked_add_mismatch() {
+        let a = mass!(1.0, MassUnit::Kilogram);
+        let b = time!(1.0, TimeUnit::Second);
+        let err = a.checked_add(&b).unwrap_err();
+        assert_eq!(
+            EngUnitError::DimensionMismatch {
+                left: "kg".to_string(),
+                right: "s".to_string()
+            },
+            err
+        );
+        assert!(a.checked_sub(&b).is_err());
+        assert!(!same_dimensions(&a, &b));
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_mismatch_panics() {
+        let a = mass!(1.0, MassUnit::Kilogram);
+        let b = time!(1.0, TimeUnit::Second);
+        let _ = a + b;
+    }
+
+    #[test]
+    fn neg() {
+        let a = mass!(1.5, MassUnit::Kilogram);
+        let b = -&a;
+        assert_eq!(-1.5, b.value);
+        assert_eq!("-1.50 kg", (-a).to_string());
+    }
+
+    #[test]
+    fn assign_operators() {
+        let mut a = mass!(1.0, MassUnit::Kilogram);
+        a += mass!(2.0, MassUnit::Kilogram);
+        assert_eq!(3.0, a.value);
+        a -= mass!(0.5, MassUnit::Kilogram);
+        assert_eq!(2.5, a.value);
+        a *= 2.0;
+        assert_eq!(5.0, a.value);
+        a /= 5.0;
+        assert_eq!(1.0, a.value);
+        a *= time!(4.0, TimeUnit::Second);
+        assert_eq!("4.00 kg·s", a.to_string());
+        a /= time!(2.0, TimeUnit::Second);
+        assert_eq!("2.00 kg", a.to_string());
+        assert_eq!("1.00 kg", (a / 2.0).to_string());
+    }
+
+    #[test]
+    fn try_convert() {
+        let a = mass!(1.0, MassUnit::Kilogram);
+        let b = a.try_convert(MassUnit::Pound).unwrap();
+        assert_eq!(MassUnit::Pound, b.mass_unit);
+        assert!(f64::abs(2.204_622_62 - b.value) < 1e-6);
+    }
+
+    #[test]
+    fn try_convert_missing_dimension() {
+        let a = mass!(1.0, MassUnit::Kilogram);
+        let err = a.try_convert(LengthUnit::Foot).unwrap_err();
+        assert_eq!(
+            EngUnitError::DimensionMismatch {
+                left: "kg".to_string(),
+                right: "ft".to_string()
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn try_convert_inside_complex_unit() {
+        let a = kJ!(1.0);
+        let err = a.try_convert(LengthUnit::Foot).unwrap_err();
+        assert_eq!(
+            EngUnitError::IncompatibleComplexUnit {
+                unit: "ft".to_string(),
+                complex: "kJ".to_string()
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn try_to_si_units() {
+        let a = mass!(1.0, MassUnit::Pound);
+        let b = a.try_to_si_units().unwrap();
+        assert_eq!(MassUnit::Kilogram, b.mass_unit);
+
+        let mut c = mass!(1.0, MassUnit::Pound);
+        c.value = f64::NAN;
+        assert!(matches!(
+            c.try_to_si_units(),
+            Err(EngUnitError::NonFiniteValue(_))
+        ));
+
+        let mut d = mass!(1.0, MassUnit::Kilogram);
+        d.value = f64::MAX;
+        assert_eq!(
+            EngUnitError::NonFiniteValue(f64::INFINITY),
+            d.try_convert(MassUnit::Pound).unwrap_err()
+        );
+    }
+
     #[test]
     fn temperature_k_div_r() {
         let unit_1 = temperature!(1.0, TemperatureDeltaUnit::K);
@@ -1154,4 +1649,132 @@ mod tests {
         assert_eq!(TemperatureDeltaUnit::None, unit_3.temperature_unit);
         assert!(!unit_3.has_units())
     }
+
+    fn base_unit(mass: i32, length: i32, time: i32, current: i32) -> EngUnit {
+        let mut unit = EngUnit::new();
+        unit.value = 2.0;
+        unit.mass_count = mass;
+        unit.mass_unit = MassUnit::Kilogram;
+        unit.length_count = length;
+        unit.length_unit = LengthUnit::Meter;
+        unit.time_count = time;
+        unit.time_unit = TimeUnit::Second;
+        unit.electric_current_count = current;
+        unit.electric_current_unit = ElectricCurrentUnit::Ampere;
+        unit.clear_unused_units();
+        unit
+    }
+
+    #[test]
+    fn simplify_named_units() {
+        assert_eq!("2.00 N", base_unit(1, 1, -2, 0).to_simplified_string());
+        assert_eq!("2.00 J", base_unit(1, 2, -2, 0).to_simplified_string());
+        assert_eq!("2.00 W", base_unit(1, 2, -3, 0).to_simplified_string());
+        assert_eq!("2.00 Pa", base_unit(1, -1, -2, 0).to_simplified_string());
+        assert_eq!("2.00 V", base_unit(1, 2, -3, -1).to_simplified_string());
+        assert_eq!("2.00 Ω", base_unit(1, 2, -3, -2).to_simplified_string());
+        assert_eq!("2.00 C", base_unit(0, 0, 1, 1).to_simplified_string());
+        assert_eq!("2.00 F", base_unit(-1, -2, 4, 2).to_simplified_string());
+    }
+
+    #[test]
+    fn simplify_keeps_leftover_exponents() {
+        assert_eq!("2.00 m/s", base_unit(0, 1, -1, 0).to_simplified_string());
+        assert_eq!("2.00 /s", base_unit(0, 0, -1, 0).to_simplified_string());
+        assert_eq!("2.00 kg/s^2", base_unit(1, 0, -2, 0).to_simplified_string());
+        let unit = base_unit(1, 1, -2, 0) * time!(1.0, TimeUnit::Second);
+        assert_eq!("2.00 kg·m/s", unit.to_simplified_string());
+    }
+
+    #[test]
+    fn simplify_converts_to_si() {
+        let mut unit = base_unit(1, 1, -2, 0);
+        unit.mass_unit = MassUnit::Pound;
+        unit.length_unit = LengthUnit::Foot;
+        let simplified = unit.simplify();
+        assert_eq!("N", simplified.unit_to_string());
+        let expected = 2.0 * 0.453_592_37 * 0.3048;
+        assert!(f64::abs(expected - simplified.value) < 1e-12);
+    }
+
+    #[test]
+    fn display_precision_and_padding() {
+        let unit = mass!(3e-9, MassUnit::Kilogram);
+        assert_eq!("0.00 kg", format!("{}", unit));
+        assert_eq!("0.000000003 kg", format!("{:.9}", unit));
+        let unit = mass!(1.5, MassUnit::Kilogram);
+        assert_eq!("1.500000 kg", format!("{:.6}", unit));
+        assert_eq!("   1.50 kg", format!("{:10}", unit));
+        assert_eq!("1.50 kg   ", format!("{:<10}", unit));
+        assert_eq!("*1.50 kg**", format!("{:*^10}", unit));
+        assert_eq!("+1.50 kg", format!("{:+}", unit));
+        assert_eq!("-1.50 kg", format!("{:+}", -unit));
+    }
+
+    #[test]
+    fn display_notation() {
+        let unit = mass!(3e-9, MassUnit::Kilogram);
+        assert_eq!("3.00e-9 kg", format!("{:e}", unit));
+        assert_eq!("3.0E-9 kg", format!("{:.1E}", unit));
+        let unit = mass!(12345.0, MassUnit::Kilogram);
+        assert_eq!("12.35e3 kg", format!("{:#}", unit));
+        assert_eq!("12.345e3 kg", format!("{:#.3}", unit));
+        let options = FormatOptions::new().notation(Notation::SignificantFigures(2));
+        assert_eq!("12000 kg", unit.to_string_with(&options));
+        let options = FormatOptions::new()
+            .precision(1)
+            .auto_prefix(crate::prefix::ENGINEERING_PREFIXES);
+        let unit = length!(0.0047, LengthUnit::Meter);
+        assert_eq!("4.7 mm", unit.to_string_with(&options));
+    }
+
+    #[test]
+    fn ascii_string() {
+        let u1 = temperature!(4.0, TemperatureDeltaUnit::C);
+        let u2 = mass!(5.0, MassUnit::Kilogram);
+        let u3 = time!(10.0, TimeUnit::Second);
+        let unit = u1 * u2 / u3;
+        assert_eq!("kg*degC/s", unit.unit_to_ascii_string());
+        assert_eq!("2.00 kg*degC/s", unit.to_ascii_string());
+        assert_eq!("s/kg*degC", unit.reciprocal().unit_to_ascii_string());
+        let unit = length!(3.0, LengthUnit::Micrometer) * length!(1.0, LengthUnit::Micrometer);
+        assert_eq!("3.00 um^2", unit.to_ascii_string());
+        assert_eq!("2.00 kohm", crate::kohm!(2.0).to_ascii_string());
+        assert_eq!("2.00 ft*lbf", crate::ft_lbf!(2.0).to_ascii_string());
+        let options = FormatOptions::new().precision(1).ascii(true);
+        assert_eq!(
+            "4.2 kJ/degF",
+            (kJ!(4.2) / temperature!(1.0, TemperatureDeltaUnit::F)).to_string_with(&options)
+        );
+    }
+
+    #[test]
+    fn canonical_order() {
+        let unit = crate::W!(2.0) * kJ!(1.0);
+        assert_eq!("kJ·W", unit.unit_to_string());
+        let unit = kJ!(1.0) * crate::W!(2.0);
+        assert_eq!("kJ·W", unit.unit_to_string());
+        let u1 = time!(1.0, TimeUnit::Second);
+        let u2 = mass!(1.0, MassUnit::Kilogram);
+        let u3 = length!(1.0, LengthUnit::Meter);
+        assert_eq!("kg·m·s", (u1 * u2 * u3).unit_to_string());
+    }
+
+    #[test]
+    fn as_written_order() {
+        let options = FormatOptions::new().unit_order(UnitOrder::AsWritten);
+        let u1 = temperature!(4.0, TemperatureDeltaUnit::C);
+        let u2 = mass!(5.0, MassUnit::Kilogram);
+        let u3 = time!(10.0, TimeUnit::Second);
+        let unit = u1 * u2 / u3;
+        assert_eq!("2.00 °C·kg/s", unit.to_string_with(&options));
+        assert_eq!("2.00 kg·°C/s", unit.to_string());
+
+        let unit = crate::W!(2.0) * kJ!(1.0);
+        assert_eq!("2.00 W·kJ", unit.to_string_with(&options));
+
+        let unit: EngUnit = "3 s·m/kg".parse().unwrap();
+        assert_eq!("3.00 s·m/kg", unit.to_string_with(&options));
+        assert_eq!("3.00 m·s/kg", unit.to_string());
+    }
 }
@@ -0,0 +1,306 @@
+// eng-units - engineering unit conversion and calculation library
+// Copyright (C) 2023 Frank Pereny
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::EngUnitError;
+use crate::units::same_dimensions;
+use crate::units::temperature_unit::TemperatureDeltaUnit;
+use crate::units::EngUnit;
+
+use std::fmt::Display;
+use std::ops;
+
+#[macro_export]
+macro_rules! absolute_temperature {
+    ($value:expr, $unit:expr) => {{
+        $crate::units::absolute_temperature_unit::AbsoluteTemperature::new($value, $unit)
+    }};
+}
+
+pub const CELSIUS_OFFSET_KELVIN: f64 = 273.15;
+pub const FAHRENHEIT_OFFSET_RANKINE: f64 = 459.67;
+
+/// Scale of an absolute (point-on-the-scale) temperature.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum AbsoluteTemperatureUnit {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+    Rankine,
+}
+
+impl AbsoluteTemperatureUnit {
+    pub fn to_string(&self) -> &'static str {
+        self.delta_unit().to_string()
+    }
+
+    pub fn from_string(s: &str) -> Option<AbsoluteTemperatureUnit> {
+        match s {
+            "°C" => Some(AbsoluteTemperatureUnit::Celsius),
+            "°F" => Some(AbsoluteTemperatureUnit::Fahrenheit),
+            "K" => Some(AbsoluteTemperatureUnit::Kelvin),
+            "R" => Some(AbsoluteTemperatureUnit::Rankine),
+            _ => None,
+        }
+    }
+
+    /// Unit used for differences between two temperatures on this scale.
+    pub fn delta_unit(&self) -> TemperatureDeltaUnit {
+        match self {
+            AbsoluteTemperatureUnit::Celsius => TemperatureDeltaUnit::C,
+            AbsoluteTemperatureUnit::Fahrenheit => TemperatureDeltaUnit::F,
+            AbsoluteTemperatureUnit::Kelvin => TemperatureDeltaUnit::K,
+            AbsoluteTemperatureUnit::Rankine => TemperatureDeltaUnit::R,
+        }
+    }
+
+    /// `true` if zero on this scale is not absolute zero.
+    pub fn has_offset(&self) -> bool {
+        matches!(
+            self,
+            AbsoluteTemperatureUnit::Celsius | AbsoluteTemperatureUnit::Fahrenheit
+        )
+    }
+
+    pub fn to_kelvin(&self, value: f64) -> f64 {
+        match self {
+            AbsoluteTemperatureUnit::Celsius => value + CELSIUS_OFFSET_KELVIN,
+            AbsoluteTemperatureUnit::Fahrenheit => (value + FAHRENHEIT_OFFSET_RANKINE) * 5.0 / 9.0,
+            AbsoluteTemperatureUnit::Kelvin => value,
+            AbsoluteTemperatureUnit::Rankine => value * 5.0 / 9.0,
+        }
+    }
+
+    pub fn from_kelvin(&self, kelvin: f64) -> f64 {
+        match self {
+            AbsoluteTemperatureUnit::Celsius => kelvin - CELSIUS_OFFSET_KELVIN,
+            AbsoluteTemperatureUnit::Fahrenheit => kelvin * 9.0 / 5.0 - FAHRENHEIT_OFFSET_RANKINE,
+            AbsoluteTemperatureUnit::Kelvin => kelvin,
+            AbsoluteTemperatureUnit::Rankine => kelvin * 9.0 / 5.0,
+        }
+    }
+}
+
+/// A temperature reading, as opposed to a temperature difference.
+///
+/// Conversions apply the scale offsets, so 20 °C converts to 293.15 K. Use
+/// [`TemperatureDeltaUnit`] on an [`EngUnit`] for differences.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AbsoluteTemperature {
+    pub value: f64,
+    pub unit: AbsoluteTemperatureUnit,
+}
+
+impl Display for AbsoluteTemperature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.2} {}", self.value, self.unit.to_string())
+    }
+}
+
+impl AbsoluteTemperature {
+    pub fn new(value: f64, unit: AbsoluteTemperatureUnit) -> Self {
+        AbsoluteTemperature { value, unit }
+    }
+
+    pub fn to_kelvin(&self) -> f64 {
+        self.unit.to_kelvin(self.value)
+    }
+
+    pub fn convert(&self, unit: AbsoluteTemperatureUnit) -> AbsoluteTemperature {
+        AbsoluteTemperature::new(unit.from_kelvin(self.to_kelvin()), unit)
+    }
+
+    /// Adds a temperature difference, returning a temperature on the same
+    /// scale as `self`.
+    pub fn checked_add(&self, delta: &EngUnit) -> Result<AbsoluteTemperature, EngUnitError> {
+        let delta = self.delta_value(delta)?;
+        Ok(AbsoluteTemperature::new(self.value + delta, self.unit))
+    }
+
+    /// Subtracts a temperature difference, returning a temperature on the
+    /// same scale as `self`.
+    pub fn checked_sub(&self, delta: &EngUnit) -> Result<AbsoluteTemperature, EngUnitError> {
+        let delta = self.delta_value(delta)?;
+        Ok(AbsoluteTemperature::new(self.value - delta, self.unit))
+    }
+
+    /// Multiplies the temperature by `other`.
+    ///
+    /// Only Kelvin and Rankine readings can be multiplied; Celsius and
+    /// Fahrenheit return [`EngUnitError::OffsetTemperature`].
+    pub fn checked_mul(&self, other: &EngUnit) -> Result<EngUnit, EngUnitError> {
+        let unit = EngUnit::try_from(*self)?;
+        Ok(unit * other.clone())
+    }
+
+    fn delta_value(&self, delta: &EngUnit) -> Result<f64, EngUnitError> {
+        let kelvin = crate::temperature!(1.0, TemperatureDeltaUnit::K);
+        if !same_dimensions(delta, &kelvin) {
+            return Err(EngUnitError::DimensionMismatch {
+                left: self.unit.to_string().to_string(),
+                right: delta.unit_to_string(),
+            });
+        }
+        Ok(delta.try_convert(self.unit.delta_unit())?.value)
+    }
+}
+
+impl TryFrom<AbsoluteTemperature> for EngUnit {
+    type Error = EngUnitError;
+
+    /// Kelvin and Rankine readings are proportional to thermodynamic
+    /// temperature and convert directly; offset scales are rejected.
+    fn try_from(value: AbsoluteTemperature) -> Result<Self, Self::Error> {
+        if value.unit.has_offset() {
+            return Err(EngUnitError::OffsetTemperature(
+                value.unit.to_string().to_string(),
+            ));
+        }
+        let mut unit = EngUnit::new();
+        unit.value = value.value;
+        unit.temperature_count = 1;
+        unit.temperature_unit = value.unit.delta_unit();
+        Ok(unit)
+    }
+}
+
+impl ops::Sub for AbsoluteTemperature {
+    type Output = EngUnit;
+
+    /// Difference between two readings, expressed as a temperature delta in
+    /// the scale of the left-hand side.
+    fn sub(self, rhs: Self) -> Self::Output {
+        let rhs = rhs.convert(self.unit);
+        let mut unit = EngUnit::new();
+        unit.value = self.value - rhs.value;
+        unit.temperature_count = 1;
+        unit.temperature_unit = self.unit.delta_unit();
+        unit
+    }
+}
+
+impl ops::Add<EngUnit> for AbsoluteTemperature {
+    type Output = AbsoluteTemperature;
+
+    fn add(self, rhs: EngUnit) -> Self::Output {
+        match self.checked_add(&rhs) {
+            Ok(t) => t,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl ops::Sub<EngUnit> for AbsoluteTemperature {
+    type Output = AbsoluteTemperature;
+
+    fn sub(self, rhs: EngUnit) -> Self::Output {
+        match self.checked_sub(&rhs) {
+            Ok(t) => t,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::EngUnitError;
+    use crate::testing::assert_close;
+    use crate::units::absolute_temperature_unit::*;
+    use crate::{s, time};
+    use crate::{temperature, TimeUnit};
+
+    #[test]
+    fn convert_with_offsets() {
+        let t = absolute_temperature!(20.0, AbsoluteTemperatureUnit::Celsius);
+        assert_close(293.15, t.convert(AbsoluteTemperatureUnit::Kelvin).value);
+        assert_close(68.0, t.convert(AbsoluteTemperatureUnit::Fahrenheit).value);
+        assert_close(527.67, t.convert(AbsoluteTemperatureUnit::Rankine).value);
+
+        let t = absolute_temperature!(-40.0, AbsoluteTemperatureUnit::Fahrenheit);
+        assert_close(-40.0, t.convert(AbsoluteTemperatureUnit::Celsius).value);
+
+        let t = absolute_temperature!(0.0, AbsoluteTemperatureUnit::Kelvin);
+        assert_close(
+            -459.67,
+            t.convert(AbsoluteTemperatureUnit::Fahrenheit).value,
+        );
+    }
+
+    #[test]
+    fn to_string() {
+        let t = absolute_temperature!(20.0, AbsoluteTemperatureUnit::Celsius);
+        assert_eq!("20.00 °C", t.to_string());
+        let t = absolute_temperature!(300.0, AbsoluteTemperatureUnit::Kelvin);
+        assert_eq!("300.00 K", t.to_string());
+    }
+
+    #[test]
+    fn difference_is_delta() {
+        let t1 = absolute_temperature!(80.0, AbsoluteTemperatureUnit::Celsius);
+        let t2 = absolute_temperature!(68.0, AbsoluteTemperatureUnit::Fahrenheit);
+        let delta = t1 - t2;
+        assert_close(60.0, delta.value);
+        assert_eq!(TemperatureDeltaUnit::C, delta.temperature_unit);
+        assert_eq!(1, delta.temperature_count);
+        assert_eq!("60.00 °C", delta.to_string());
+    }
+
+    #[test]
+    fn add_delta() {
+        let t = absolute_temperature!(20.0, AbsoluteTemperatureUnit::Celsius);
+        let t = t + temperature!(18.0, TemperatureDeltaUnit::F);
+        assert_close(30.0, t.value);
+        assert_eq!(AbsoluteTemperatureUnit::Celsius, t.unit);
+
+        let t = t - temperature!(10.0, TemperatureDeltaUnit::K);
+        assert_close(20.0, t.value);
+    }
+
+    #[test]
+    fn add_non_temperature() {
+        let t = absolute_temperature!(20.0, AbsoluteTemperatureUnit::Celsius);
+        let err = t.checked_add(&s!(1.0)).unwrap_err();
+        assert!(matches!(err, EngUnitError::DimensionMismatch { .. }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_non_temperature_panics() {
+        let t = absolute_temperature!(20.0, AbsoluteTemperatureUnit::Celsius);
+        let _ = t + time!(1.0, TimeUnit::Second);
+    }
+
+    #[test]
+    fn multiply_offset_scale_rejected() {
+        let t = absolute_temperature!(20.0, AbsoluteTemperatureUnit::Celsius);
+        let err = t.checked_mul(&s!(2.0)).unwrap_err();
+        assert_eq!(EngUnitError::OffsetTemperature("°C".to_string()), err);
+        assert!(EngUnit::try_from(t).is_err());
+
+        let t = absolute_temperature!(20.0, AbsoluteTemperatureUnit::Fahrenheit);
+        assert!(t.checked_mul(&s!(2.0)).is_err());
+    }
+
+    #[test]
+    fn multiply_absolute_scale() {
+        let t = absolute_temperature!(300.0, AbsoluteTemperatureUnit::Kelvin);
+        let unit = t.checked_mul(&s!(2.0)).unwrap();
+        assert_eq!("600.00 s·K", unit.to_string());
+
+        let t = absolute_temperature!(20.0, AbsoluteTemperatureUnit::Celsius);
+        let unit = EngUnit::try_from(t.convert(AbsoluteTemperatureUnit::Kelvin)).unwrap();
+        assert_close(293.15, unit.value);
+    }
+}
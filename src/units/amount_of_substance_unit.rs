@@ -45,6 +45,13 @@ impl AmountOfSubstanceUnit {
             AmountOfSubstanceUnit::None => "",
         }
     }
+
+    pub fn from_string(s: &str) -> Option<AmountOfSubstanceUnit> {
+        match s {
+            "mol" => Some(AmountOfSubstanceUnit::Mol),
+            _ => None,
+        }
+    }
 }
 
 impl IsEngUnitType for AmountOfSubstanceUnit {
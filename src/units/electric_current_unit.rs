@@ -45,6 +45,13 @@ impl ElectricCurrentUnit {
             ElectricCurrentUnit::None => "",
         }
     }
+
+    pub fn from_string(s: &str) -> Option<ElectricCurrentUnit> {
+        match s {
+            "A" => Some(ElectricCurrentUnit::Ampere),
+            _ => None,
+        }
+    }
 }
 
 impl<
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use crate::latex::latex_symbol;
 use crate::units::AmountOfSubstanceUnit;
 use crate::units::ElectricCurrentUnit;
 use crate::units::IsEngUnitType;
@@ -22,37 +23,264 @@ use crate::units::MassUnit;
 use crate::units::TemperatureDeltaUnit;
 use crate::units::TimeUnit;
 
+/// Create new unit of length
+#[macro_export]
+macro_rules! length {
+    ($value:literal, $unit:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        if $unit == LengthUnit::None {
+            unit.length_count = 0;
+        }
+        unit.length_unit = $unit;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! length_m {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        unit.length_unit = LengthUnit::Meter;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! length_nm {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        unit.length_unit = LengthUnit::Nanometer;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! length_um {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        unit.length_unit = LengthUnit::Micrometer;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! length_mm {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        unit.length_unit = LengthUnit::Millimeter;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! length_cm {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        unit.length_unit = LengthUnit::Centimeter;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! length_km {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        unit.length_unit = LengthUnit::Kilometer;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! length_in {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        unit.length_unit = LengthUnit::Inch;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! length_ft {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        unit.length_unit = LengthUnit::Foot;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! length_yd {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        unit.length_unit = LengthUnit::Yard;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! length_mi {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        unit.length_unit = LengthUnit::Mile;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! length_nmi {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        unit.length_unit = LengthUnit::NauticalMile;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! length_mil {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        unit.length_unit = LengthUnit::Mil;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! length_thou {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.length_count = 1;
+        unit.length_unit = LengthUnit::Thou;
+        unit
+    }};
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum LengthUnit {
     Meter,
+    Nanometer,
+    Micrometer,
+    Millimeter,
+    Centimeter,
+    Kilometer,
+    Inch,
     Foot,
+    Yard,
+    Mile,
+    NauticalMile,
+    Mil,
+    Thou,
     None,
 }
 
+/// International inch, exactly 25.4 mm.
+pub const INCH_TO_METERS: f64 = 0.0254;
+/// 12 in
+pub const FOOT_TO_METERS: f64 = 0.3048;
+/// 3 ft
+pub const YARD_TO_METERS: f64 = 0.9144;
+/// 5280 ft
+pub const MILE_TO_METERS: f64 = 1609.344;
+pub const NAUTICAL_MILE_TO_METERS: f64 = 1852.0;
+/// One mil (or thou) is a thousandth of an inch.
+pub const MIL_TO_METERS: f64 = 0.0000254;
+
 impl LengthUnit {
+    /// Length of one unit in meters.
+    pub fn to_meters(&self) -> f64 {
+        match self {
+            LengthUnit::Meter => 1.0,
+            LengthUnit::Nanometer => 1.0e-9,
+            LengthUnit::Micrometer => 1.0e-6,
+            LengthUnit::Millimeter => 1.0e-3,
+            LengthUnit::Centimeter => 1.0e-2,
+            LengthUnit::Kilometer => 1.0e3,
+            LengthUnit::Inch => INCH_TO_METERS,
+            LengthUnit::Foot => FOOT_TO_METERS,
+            LengthUnit::Yard => YARD_TO_METERS,
+            LengthUnit::Mile => MILE_TO_METERS,
+            LengthUnit::NauticalMile => NAUTICAL_MILE_TO_METERS,
+            LengthUnit::Mil => MIL_TO_METERS,
+            LengthUnit::Thou => MIL_TO_METERS,
+            LengthUnit::None => 1.0,
+        }
+    }
+
     pub fn conversion_factor(from: &LengthUnit, to: &LengthUnit) -> f64 {
-        match from {
-            Self::Meter => match to {
-                Self::Meter => 1.0,
-                Self::Foot => 3.28084,
-                Self::None => 1.0,
-            },
-            Self::Foot => match to {
-                Self::Meter => 1.0 / 3.28084,
-                Self::Foot => 1.0,
-                Self::None => 1.0,
-            },
-            Self::None => 1.0,
+        if *from == LengthUnit::None || *to == LengthUnit::None {
+            return 1.0;
         }
+        from.to_meters() / to.to_meters()
     }
 
     pub fn to_string(&self) -> &'static str {
         match self {
             LengthUnit::Meter => "m",
+            LengthUnit::Nanometer => "nm",
+            LengthUnit::Micrometer => "µm",
+            LengthUnit::Millimeter => "mm",
+            LengthUnit::Centimeter => "cm",
+            LengthUnit::Kilometer => "km",
+            LengthUnit::Inch => "in",
             LengthUnit::Foot => "ft",
+            LengthUnit::Yard => "yd",
+            LengthUnit::Mile => "mi",
+            LengthUnit::NauticalMile => "nmi",
+            LengthUnit::Mil => "mil",
+            LengthUnit::Thou => "thou",
             LengthUnit::None => "",
         }
     }
+
+    /// The symbol in LaTeX, spelled like [`crate::EngUnit::to_latex`] spells it.
+    pub fn to_latex(&self) -> String {
+        latex_symbol(self.to_string())
+    }
+
+    pub fn from_string(s: &str) -> Option<LengthUnit> {
+        match s {
+            "m" => Some(LengthUnit::Meter),
+            "nm" => Some(LengthUnit::Nanometer),
+            "µm" => Some(LengthUnit::Micrometer),
+            "mm" => Some(LengthUnit::Millimeter),
+            "cm" => Some(LengthUnit::Centimeter),
+            "km" => Some(LengthUnit::Kilometer),
+            "in" => Some(LengthUnit::Inch),
+            "ft" => Some(LengthUnit::Foot),
+            "yd" => Some(LengthUnit::Yard),
+            "mi" => Some(LengthUnit::Mile),
+            "nmi" => Some(LengthUnit::NauticalMile),
+            "mil" => Some(LengthUnit::Mil),
+            "thou" => Some(LengthUnit::Thou),
+            _ => None,
+        }
+    }
 }
 
 impl<
@@ -109,3 +337,85 @@ impl From<TimeUnit> for LengthUnit {
         LengthUnit::None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::assert_close;
+    use crate::units::length_unit::*;
+    use crate::units::EngUnit;
+
+    #[test]
+    fn exact_definitions() {
+        assert_eq!(0.0254, LengthUnit::Inch.to_meters());
+        assert_eq!(0.3048, LengthUnit::Foot.to_meters());
+        assert_eq!(0.9144, LengthUnit::Yard.to_meters());
+        assert_eq!(1609.344, LengthUnit::Mile.to_meters());
+        assert_eq!(1852.0, LengthUnit::NauticalMile.to_meters());
+        assert_eq!(0.0000254, LengthUnit::Mil.to_meters());
+        assert_eq!(LengthUnit::Mil.to_meters(), LengthUnit::Thou.to_meters());
+    }
+
+    #[test]
+    fn conversion_factors() {
+        let f = LengthUnit::conversion_factor(&LengthUnit::Inch, &LengthUnit::Millimeter);
+        assert_close(25.4, f);
+        let f = LengthUnit::conversion_factor(&LengthUnit::Foot, &LengthUnit::Inch);
+        assert_close(12.0, f);
+        let f = LengthUnit::conversion_factor(&LengthUnit::Mile, &LengthUnit::Yard);
+        assert_close(1760.0, f);
+        let f = LengthUnit::conversion_factor(&LengthUnit::Kilometer, &LengthUnit::Meter);
+        assert_close(1000.0, f);
+        let f = LengthUnit::conversion_factor(&LengthUnit::Micrometer, &LengthUnit::Nanometer);
+        assert_close(1000.0, f);
+        let f = LengthUnit::conversion_factor(&LengthUnit::Inch, &LengthUnit::Thou);
+        assert_close(1000.0, f);
+        let f = LengthUnit::conversion_factor(&LengthUnit::Meter, &LengthUnit::Centimeter);
+        assert_close(100.0, f);
+        let f = LengthUnit::conversion_factor(&LengthUnit::NauticalMile, &LengthUnit::Meter);
+        assert_close(1852.0, f);
+    }
+
+    #[test]
+    fn conversion_with_none() {
+        assert_eq!(
+            1.0,
+            LengthUnit::conversion_factor(&LengthUnit::None, &LengthUnit::Inch)
+        );
+        assert_eq!(
+            1.0,
+            LengthUnit::conversion_factor(&LengthUnit::Mile, &LengthUnit::None)
+        );
+    }
+
+    #[test]
+    fn convert_area() {
+        let l = length_ft!(1.0) * length_ft!(1.0);
+        let l = l.convert(LengthUnit::Inch);
+        assert_close(144.0, l.value);
+        assert_eq!("144.00 in^2", l.to_string());
+    }
+
+    #[test]
+    fn macros() {
+        assert_eq!("2.00 mm", length_mm!(2.0).to_string());
+        assert_eq!("2.00 µm", length_um!(2.0).to_string());
+        assert_eq!("2.00 km", length_km!(2.0).to_string());
+        assert_eq!("2.00 in", length_in!(2.0).to_string());
+        assert_eq!("2.00 mi", length_mi!(2.0).to_string());
+        assert_eq!(
+            "2.00 nmi",
+            length!(2.0, LengthUnit::NauticalMile).to_string()
+        );
+        assert_eq!("2.00", length!(2.0, LengthUnit::None).to_string());
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!("\\mu\\mathrm{m}", LengthUnit::Micrometer.to_latex());
+        assert_eq!("\\mathrm{ft}", LengthUnit::Foot.to_latex());
+        assert_eq!("", LengthUnit::None.to_latex());
+        assert_eq!(Some(LengthUnit::Yard), LengthUnit::from_string("yd"));
+        assert_eq!(Some(LengthUnit::Thou), LengthUnit::from_string("thou"));
+        assert_eq!(None, LengthUnit::from_string("furlong"));
+    }
+}
@@ -45,6 +45,13 @@ impl LuminousIntensityUnit {
             LuminousIntensityUnit::None => "",
         }
     }
+
+    pub fn from_string(s: &str) -> Option<LuminousIntensityUnit> {
+        match s {
+            "cd" => Some(LuminousIntensityUnit::Candela),
+            _ => None,
+        }
+    }
 }
 
 impl<
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use crate::constants::STANDARD_GRAVITY;
+use crate::units::length_unit::FOOT_TO_METERS;
 use crate::units::AmountOfSubstanceUnit;
 use crate::units::ElectricCurrentUnit;
 use crate::units::IsEngUnitType;
@@ -59,10 +61,130 @@ macro_rules! mass_lb {
     }};
 }
 
+#[macro_export]
+macro_rules! mass_g {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.mass_count = 1;
+        unit.mass_unit = MassUnit::Gram;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! mass_mg {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.mass_count = 1;
+        unit.mass_unit = MassUnit::Milligram;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! mass_ug {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.mass_count = 1;
+        unit.mass_unit = MassUnit::Microgram;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! mass_t {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.mass_count = 1;
+        unit.mass_unit = MassUnit::Tonne;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! mass_oz {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.mass_count = 1;
+        unit.mass_unit = MassUnit::Ounce;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! mass_slug {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.mass_count = 1;
+        unit.mass_unit = MassUnit::Slug;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! mass_short_ton {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.mass_count = 1;
+        unit.mass_unit = MassUnit::ShortTon;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! mass_long_ton {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.mass_count = 1;
+        unit.mass_unit = MassUnit::LongTon;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! mass_gr {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.mass_count = 1;
+        unit.mass_unit = MassUnit::Grain;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! mass_u {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.mass_count = 1;
+        unit.mass_unit = MassUnit::AtomicMassUnit;
+        unit
+    }};
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum MassUnit {
     Kilogram,
     Pound,
+    Gram,
+    Milligram,
+    Microgram,
+    Tonne,
+    Ounce,
+    Slug,
+    ShortTon,
+    LongTon,
+    Grain,
+    AtomicMassUnit,
     None,
 }
 
@@ -121,41 +243,94 @@ impl From<TimeUnit> for MassUnit {
     }
 }
 
-pub const KILOGRAM_TO_POUND: f64 = 2.204_622_62;
+/// International avoirdupois pound, exactly 0.453 592 37 kg.
+pub const POUND_TO_KILOGRAMS: f64 = 0.453_592_37;
+pub const KILOGRAM_TO_POUND: f64 = 1.0 / POUND_TO_KILOGRAMS;
+/// 1/16 lb
+pub const OUNCE_TO_KILOGRAMS: f64 = 0.028_349_523_125;
+/// 1/7000 lb
+pub const GRAIN_TO_KILOGRAMS: f64 = 0.000_064_798_91;
+/// 2000 lb
+pub const SHORT_TON_TO_KILOGRAMS: f64 = 907.184_74;
+/// 2240 lb
+pub const LONG_TON_TO_KILOGRAMS: f64 = 1_016.046_908_8;
+/// 1 lbf·s^2/ft, using standard gravity.
+pub const SLUG_TO_KILOGRAMS: f64 = POUND_TO_KILOGRAMS * STANDARD_GRAVITY / FOOT_TO_METERS;
+/// Unified atomic mass unit (dalton), CODATA 2018.
+pub const ATOMIC_MASS_UNIT_TO_KILOGRAMS: f64 = 1.660_539_066_60e-27;
 
 impl MassUnit {
     pub fn to_string(&self) -> &'static str {
         match self {
             MassUnit::Kilogram => "kg",
             MassUnit::Pound => "lb",
+            MassUnit::Gram => "g",
+            MassUnit::Milligram => "mg",
+            MassUnit::Microgram => "µg",
+            MassUnit::Tonne => "t",
+            MassUnit::Ounce => "oz",
+            MassUnit::Slug => "slug",
+            MassUnit::ShortTon => "ton",
+            MassUnit::LongTon => "LT",
+            MassUnit::Grain => "gr",
+            MassUnit::AtomicMassUnit => "u",
             MassUnit::None => "",
         }
     }
 
-    pub fn conversion_factor(from: &MassUnit, to: &MassUnit) -> f64 {
-        match from {
-            MassUnit::Kilogram => match to {
-                MassUnit::Kilogram => 1.0,
-                MassUnit::Pound => KILOGRAM_TO_POUND,
-                MassUnit::None => 1.0,
-            },
-            MassUnit::Pound => match to {
-                MassUnit::Pound => 1.0,
-                MassUnit::Kilogram => 1.0 / KILOGRAM_TO_POUND,
-                MassUnit::None => 1.0,
-            },
+    pub fn from_string(s: &str) -> Option<MassUnit> {
+        match s {
+            "kg" => Some(MassUnit::Kilogram),
+            "lb" => Some(MassUnit::Pound),
+            "g" => Some(MassUnit::Gram),
+            "mg" => Some(MassUnit::Milligram),
+            "µg" => Some(MassUnit::Microgram),
+            "t" => Some(MassUnit::Tonne),
+            "oz" => Some(MassUnit::Ounce),
+            "slug" => Some(MassUnit::Slug),
+            "ton" => Some(MassUnit::ShortTon),
+            "LT" => Some(MassUnit::LongTon),
+            "gr" => Some(MassUnit::Grain),
+            "u" => Some(MassUnit::AtomicMassUnit),
+            _ => None,
+        }
+    }
+
+    /// Mass of one unit in kilograms.
+    pub fn to_kilograms(&self) -> f64 {
+        match self {
+            MassUnit::Kilogram => 1.0,
+            MassUnit::Pound => POUND_TO_KILOGRAMS,
+            MassUnit::Gram => 1.0e-3,
+            MassUnit::Milligram => 1.0e-6,
+            MassUnit::Microgram => 1.0e-9,
+            MassUnit::Tonne => 1.0e3,
+            MassUnit::Ounce => OUNCE_TO_KILOGRAMS,
+            MassUnit::Slug => SLUG_TO_KILOGRAMS,
+            MassUnit::ShortTon => SHORT_TON_TO_KILOGRAMS,
+            MassUnit::LongTon => LONG_TON_TO_KILOGRAMS,
+            MassUnit::Grain => GRAIN_TO_KILOGRAMS,
+            MassUnit::AtomicMassUnit => ATOMIC_MASS_UNIT_TO_KILOGRAMS,
             MassUnit::None => 1.0,
         }
     }
+
+    pub fn conversion_factor(from: &MassUnit, to: &MassUnit) -> f64 {
+        if *from == MassUnit::None || *to == MassUnit::None {
+            return 1.0;
+        }
+        from.to_kilograms() / to.to_kilograms()
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::testing::assert_close;
     use crate::units::amount_of_substance_unit::AmountOfSubstanceUnit;
     use crate::units::electric_current_unit::ElectricCurrentUnit;
     use crate::units::length_unit::LengthUnit;
     use crate::units::luminous_intensity_unit::LuminousIntensityUnit;
-    use crate::units::mass_unit::MassUnit;
+    use crate::units::mass_unit::*;
     use crate::units::temperature_unit::TemperatureDeltaUnit;
     use crate::units::time_unit::TimeUnit;
     use crate::units::EngUnit;
@@ -209,7 +384,7 @@ mod tests {
     #[test]
     fn test_latex() {
         let m1 = mass!(1.0, MassUnit::Kilogram);
-        assert_eq!("$1\\ kg$", m1.to_latex())
+        assert_eq!("$1\\ \\mathrm{kg}$", m1.to_latex())
     }
 
     #[test]
@@ -236,7 +411,8 @@ mod tests {
         let m1 = mass!(1.0, MassUnit::Kilogram);
         let m2 = m1.convert(MassUnit::Pound);
         assert_eq!(1.0, m1.value);
-        assert_eq!(2.204_622_62, m2.value);
+        assert_eq!(KILOGRAM_TO_POUND, m2.value);
+        assert!(f64::abs(2.204_622_62 - m2.value) < 1e-8);
         assert_eq!(MassUnit::Kilogram, m1.mass_unit);
         assert_eq!(MassUnit::Pound, m2.mass_unit);
         assert_eq!("1.00 kg", m1.to_string());
@@ -255,4 +431,71 @@ mod tests {
         assert_eq!("1.00 lb", m1.to_string());
         assert_eq!("0.45 kg", m2.to_string());
     }
+
+    #[test]
+    fn exact_definitions() {
+        assert_close(POUND_TO_KILOGRAMS / 16.0, MassUnit::Ounce.to_kilograms());
+        assert_close(POUND_TO_KILOGRAMS / 7000.0, MassUnit::Grain.to_kilograms());
+        assert_close(
+            POUND_TO_KILOGRAMS * 2000.0,
+            MassUnit::ShortTon.to_kilograms(),
+        );
+        assert_close(
+            POUND_TO_KILOGRAMS * 2240.0,
+            MassUnit::LongTon.to_kilograms(),
+        );
+        assert_close(14.593_902_937_206_364, MassUnit::Slug.to_kilograms());
+        assert_eq!(1000.0, MassUnit::Tonne.to_kilograms());
+    }
+
+    #[test]
+    fn conversion_factors() {
+        let f = MassUnit::conversion_factor(&MassUnit::Kilogram, &MassUnit::Gram);
+        assert_close(1000.0, f);
+        let f = MassUnit::conversion_factor(&MassUnit::Gram, &MassUnit::Milligram);
+        assert_close(1000.0, f);
+        let f = MassUnit::conversion_factor(&MassUnit::Milligram, &MassUnit::Microgram);
+        assert_close(1000.0, f);
+        let f = MassUnit::conversion_factor(&MassUnit::Pound, &MassUnit::Ounce);
+        assert_close(16.0, f);
+        let f = MassUnit::conversion_factor(&MassUnit::Pound, &MassUnit::Grain);
+        assert_close(7000.0, f);
+        let f = MassUnit::conversion_factor(&MassUnit::LongTon, &MassUnit::Pound);
+        assert_close(2240.0, f);
+        let f = MassUnit::conversion_factor(&MassUnit::Slug, &MassUnit::Pound);
+        assert_close(32.174_048_556_430_45, f);
+        let f = MassUnit::conversion_factor(&MassUnit::AtomicMassUnit, &MassUnit::Kilogram);
+        assert_close(1.660_539_066_60e-27, f);
+        let f = MassUnit::conversion_factor(&MassUnit::None, &MassUnit::Tonne);
+        assert_eq!(1.0, f);
+    }
+
+    #[test]
+    fn macros() {
+        assert_eq!("2.00 g", mass_g!(2.0).to_string());
+        assert_eq!("2.00 mg", mass_mg!(2.0).to_string());
+        assert_eq!("2.00 µg", mass_ug!(2.0).to_string());
+        assert_eq!("2.00 t", mass_t!(2.0).to_string());
+        assert_eq!("2.00 oz", mass_oz!(2.0).to_string());
+        assert_eq!("2.00 slug", mass_slug!(2.0).to_string());
+        assert_eq!("2.00 ton", mass_short_ton!(2.0).to_string());
+        assert_eq!("2.00 LT", mass_long_ton!(2.0).to_string());
+        assert_eq!("2.00 gr", mass_gr!(2.0).to_string());
+        assert_eq!("2.00 u", mass_u!(2.0).to_string());
+    }
+
+    #[test]
+    fn convert_tonne_to_short_ton() {
+        let m1 = mass!(1.0, MassUnit::Tonne);
+        let m2 = m1.convert(MassUnit::ShortTon);
+        assert_close(1.102_311_310_924_388, m2.value);
+        assert_eq!("1.10 ton", m2.to_string());
+    }
+
+    #[test]
+    fn from_string() {
+        assert_eq!(Some(MassUnit::Ounce), MassUnit::from_string("oz"));
+        assert_eq!(Some(MassUnit::Microgram), MassUnit::from_string("µg"));
+        assert_eq!(None, MassUnit::from_string("stone"));
+    }
 }
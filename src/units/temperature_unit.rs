@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use crate::latex::latex_symbol;
 use crate::units::AmountOfSubstanceUnit;
 use crate::units::ElectricCurrentUnit;
 use crate::units::IsEngUnitType;
@@ -111,16 +112,21 @@ impl TemperatureDeltaUnit {
         }
     }
 
-    pub fn to_latex(&self) -> &'static str {
-        match self {
-            TemperatureDeltaUnit::R => "R",
-            TemperatureDeltaUnit::K => "K",
-            TemperatureDeltaUnit::C => "^\\circ C",
-            TemperatureDeltaUnit::F => "^\\circ F",
-            TemperatureDeltaUnit::None => "",
+    pub fn from_string(s: &str) -> Option<TemperatureDeltaUnit> {
+        match s {
+            "R" => Some(TemperatureDeltaUnit::R),
+            "K" => Some(TemperatureDeltaUnit::K),
+            "°C" => Some(TemperatureDeltaUnit::C),
+            "°F" => Some(TemperatureDeltaUnit::F),
+            _ => None,
         }
     }
 
+    /// The symbol in LaTeX, spelled like [`crate::EngUnit::to_latex`] spells it.
+    pub fn to_latex(&self) -> String {
+        latex_symbol(self.to_string())
+    }
+
     pub fn conversion_factor(from: &TemperatureDeltaUnit, to: &TemperatureDeltaUnit) -> f64 {
         match from {
             TemperatureDeltaUnit::R => match to {
@@ -541,25 +547,26 @@ mod tests {
     #[test]
     fn test_latex_1() {
         let a = temperature!(123.45, TemperatureDeltaUnit::K);
-        assert_eq!("$123.45\\ K$", a.to_latex());
+        assert_eq!("$123.45\\ \\mathrm{K}$", a.to_latex());
     }
 
     #[test]
     fn test_latex_2() {
         let a = temperature!(123.45, TemperatureDeltaUnit::R);
-        assert_eq!("$123.45\\ R$", a.to_latex());
+        assert_eq!("$123.45\\ \\mathrm{R}$", a.to_latex());
     }
 
     #[test]
     fn test_latex_3() {
         let a = temperature!(123.45, TemperatureDeltaUnit::C);
-        assert_eq!("$123.45\\ ^\\circ C$", a.to_latex());
+        assert_eq!("$123.45\\ {}^{\\circ}\\mathrm{C}$", a.to_latex());
+        assert_eq!("{}^{\\circ}\\mathrm{C}", TemperatureDeltaUnit::C.to_latex());
     }
 
     #[test]
     fn test_latex_4() {
         let a = temperature!(123.45, TemperatureDeltaUnit::F);
-        assert_eq!("$123.45\\ ^\\circ F$", a.to_latex());
+        assert_eq!("$123.45\\ {}^{\\circ}\\mathrm{F}$", a.to_latex());
     }
 
     #[test]
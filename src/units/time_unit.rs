@@ -47,11 +47,105 @@ macro_rules! s {
     }};
 }
 
+#[macro_export]
+macro_rules! time_min {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.time_count = 1;
+        unit.time_unit = TimeUnit::Minute;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! time_hr {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.time_count = 1;
+        unit.time_unit = TimeUnit::Hour;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! time_ms {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.time_count = 1;
+        unit.time_unit = TimeUnit::Millisecond;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! time_us {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.time_count = 1;
+        unit.time_unit = TimeUnit::Microsecond;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! time_ns {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.time_count = 1;
+        unit.time_unit = TimeUnit::Nanosecond;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! time_day {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.time_count = 1;
+        unit.time_unit = TimeUnit::Day;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! time_wk {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.time_count = 1;
+        unit.time_unit = TimeUnit::Week;
+        unit
+    }};
+}
+
+#[macro_export]
+macro_rules! time_yr {
+    ($value:expr) => {{
+        let mut unit = EngUnit::new();
+        unit.value = $value;
+        unit.time_count = 1;
+        unit.time_unit = TimeUnit::Year;
+        unit
+    }};
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum TimeUnit {
     Second,
     Minute,
     Hour,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+    Day,
+    Week,
+    Year,
     None,
 }
 
@@ -113,6 +207,10 @@ impl From<TemperatureDeltaUnit> for TimeUnit {
 pub const MINUTE_TO_SECONDS: f64 = 60.0;
 pub const HOUR_TO_SECONDS: f64 = 3600.0;
 pub const HOUR_TO_MINUTES: f64 = 60.0;
+pub const DAY_TO_SECONDS: f64 = 86_400.0;
+pub const WEEK_TO_SECONDS: f64 = 7.0 * DAY_TO_SECONDS;
+/// Julian year of exactly 365.25 days.
+pub const JULIAN_YEAR_TO_SECONDS: f64 = 365.25 * DAY_TO_SECONDS;
 
 impl TimeUnit {
     pub fn to_string(&self) -> &'static str {
@@ -120,31 +218,122 @@ impl TimeUnit {
             TimeUnit::Second => "s",
             TimeUnit::Minute => "min",
             TimeUnit::Hour => "hr",
+            TimeUnit::Millisecond => "ms",
+            TimeUnit::Microsecond => "µs",
+            TimeUnit::Nanosecond => "ns",
+            TimeUnit::Day => "d",
+            TimeUnit::Week => "wk",
+            TimeUnit::Year => "yr",
             TimeUnit::None => "",
         }
     }
 
-    pub fn conversion_factor(from: &TimeUnit, to: &TimeUnit) -> f64 {
-        match from {
-            TimeUnit::Second => match to {
-                TimeUnit::Second => 1.0,
-                TimeUnit::Minute => 1.0 / MINUTE_TO_SECONDS,
-                TimeUnit::Hour => 1.0 / HOUR_TO_SECONDS,
-                TimeUnit::None => 1.0,
-            },
-            TimeUnit::Minute => match to {
-                TimeUnit::Minute => 1.0,
-                TimeUnit::Second => MINUTE_TO_SECONDS,
-                TimeUnit::Hour => HOUR_TO_MINUTES,
-                TimeUnit::None => 1.0,
-            },
-            TimeUnit::Hour => match to {
-                TimeUnit::Minute => HOUR_TO_MINUTES,
-                TimeUnit::Second => HOUR_TO_SECONDS,
-                TimeUnit::Hour => 1.0,
-                TimeUnit::None => 1.0,
-            },
+    pub fn from_string(s: &str) -> Option<TimeUnit> {
+        match s {
+            "s" => Some(TimeUnit::Second),
+            "min" => Some(TimeUnit::Minute),
+            "hr" => Some(TimeUnit::Hour),
+            "ms" => Some(TimeUnit::Millisecond),
+            "µs" => Some(TimeUnit::Microsecond),
+            "ns" => Some(TimeUnit::Nanosecond),
+            "d" => Some(TimeUnit::Day),
+            "wk" => Some(TimeUnit::Week),
+            "yr" => Some(TimeUnit::Year),
+            _ => None,
+        }
+    }
+
+    /// Duration of one unit in seconds.
+    pub fn to_seconds(&self) -> f64 {
+        match self {
+            TimeUnit::Second => 1.0,
+            TimeUnit::Minute => MINUTE_TO_SECONDS,
+            TimeUnit::Hour => HOUR_TO_SECONDS,
+            TimeUnit::Millisecond => 1.0e-3,
+            TimeUnit::Microsecond => 1.0e-6,
+            TimeUnit::Nanosecond => 1.0e-9,
+            TimeUnit::Day => DAY_TO_SECONDS,
+            TimeUnit::Week => WEEK_TO_SECONDS,
+            TimeUnit::Year => JULIAN_YEAR_TO_SECONDS,
             TimeUnit::None => 1.0,
         }
     }
+
+    pub fn conversion_factor(from: &TimeUnit, to: &TimeUnit) -> f64 {
+        if *from == TimeUnit::None || *to == TimeUnit::None {
+            return 1.0;
+        }
+        from.to_seconds() / to.to_seconds()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::assert_close;
+    use crate::units::time_unit::*;
+    use crate::units::EngUnit;
+
+    #[test]
+    fn conversion_factors() {
+        let f = TimeUnit::conversion_factor(&TimeUnit::Second, &TimeUnit::Minute);
+        assert_close(1.0 / 60.0, f);
+        let f = TimeUnit::conversion_factor(&TimeUnit::Minute, &TimeUnit::Hour);
+        assert_close(1.0 / 60.0, f);
+        let f = TimeUnit::conversion_factor(&TimeUnit::Hour, &TimeUnit::Minute);
+        assert_close(60.0, f);
+        let f = TimeUnit::conversion_factor(&TimeUnit::Second, &TimeUnit::Millisecond);
+        assert_close(1000.0, f);
+        let f = TimeUnit::conversion_factor(&TimeUnit::Millisecond, &TimeUnit::Microsecond);
+        assert_close(1000.0, f);
+        let f = TimeUnit::conversion_factor(&TimeUnit::Microsecond, &TimeUnit::Nanosecond);
+        assert_close(1000.0, f);
+        let f = TimeUnit::conversion_factor(&TimeUnit::Day, &TimeUnit::Hour);
+        assert_close(24.0, f);
+        let f = TimeUnit::conversion_factor(&TimeUnit::Week, &TimeUnit::Day);
+        assert_close(7.0, f);
+        let f = TimeUnit::conversion_factor(&TimeUnit::Year, &TimeUnit::Day);
+        assert_close(365.25, f);
+        let f = TimeUnit::conversion_factor(&TimeUnit::Year, &TimeUnit::Hour);
+        assert_close(8766.0, f);
+    }
+
+    #[test]
+    fn conversion_with_none() {
+        let f = TimeUnit::conversion_factor(&TimeUnit::None, &TimeUnit::Day);
+        assert_eq!(1.0, f);
+        let f = TimeUnit::conversion_factor(&TimeUnit::Week, &TimeUnit::None);
+        assert_eq!(1.0, f);
+    }
+
+    #[test]
+    fn convert_rate() {
+        let mut unit = EngUnit::new();
+        unit.value = 1.0;
+        unit.time_count = -1;
+        unit.time_unit = TimeUnit::Millisecond;
+        let unit = unit.convert(TimeUnit::Second);
+        assert_close(1000.0, unit.value);
+        assert_eq!("1000.00 /s", unit.to_string());
+    }
+
+    #[test]
+    fn macros() {
+        assert_eq!("2.00 s", s!(2.0).to_string());
+        assert_eq!("2.00 min", time_min!(2.0).to_string());
+        assert_eq!("2.00 hr", time_hr!(2.0).to_string());
+        assert_eq!("2.00 ms", time_ms!(2.0).to_string());
+        assert_eq!("2.00 µs", time_us!(2.0).to_string());
+        assert_eq!("2.00 ns", time_ns!(2.0).to_string());
+        assert_eq!("2.00 d", time_day!(2.0).to_string());
+        assert_eq!("2.00 wk", time_wk!(2.0).to_string());
+        assert_eq!("2.00 yr", time_yr!(2.0).to_string());
+        assert_eq!("2.00 d", time!(2.0, TimeUnit::Day).to_string());
+    }
+
+    #[test]
+    fn from_string() {
+        assert_eq!(Some(TimeUnit::Microsecond), TimeUnit::from_string("µs"));
+        assert_eq!(Some(TimeUnit::Year), TimeUnit::from_string("yr"));
+        assert_eq!(None, TimeUnit::from_string("fortnight"));
+    }
 }